@@ -0,0 +1,63 @@
+// `#[accepts]` rewrites annotated parameters into `impl IntoItems`-style bounds and
+// collects them into the declared type at the start of the body.
+use itemize::{IntoItems, IntoRows, TryIntoItems, TryIntoRows, accepts};
+
+#[derive(Debug, PartialEq, IntoItems, IntoRows)]
+#[items_from(types(u32), tuples(3), collections(vec, array), wrap)]
+struct Id(u32);
+
+#[derive(Debug, PartialEq)]
+struct Int(i64);
+
+#[derive(Debug)]
+struct ParseError(#[allow(dead_code)] String);
+
+impl From<std::num::ParseIntError> for ParseError {
+    fn from(e: std::num::ParseIntError) -> Self {
+        ParseError(e.to_string())
+    }
+}
+
+#[derive(TryIntoItems, TryIntoRows)]
+#[items_from(parse(&'a str), tuples(3), collections(vec), error_type(ParseError))]
+struct ParsedInt(i64);
+
+impl std::str::FromStr for ParsedInt {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(ParsedInt)
+    }
+}
+
+#[accepts]
+fn count(#[items] ids: Vec<Id>) -> usize {
+    ids.len()
+}
+
+#[accepts]
+fn widths(#[rows] grid: Vec<Vec<Id>>) -> Vec<usize> {
+    grid.iter().map(Vec::len).collect()
+}
+
+#[accepts]
+fn sum(#[try_items(ParseError)] ints: Vec<ParsedInt>) -> Result<Int, ParseError> {
+    Ok(Int(ints.iter().map(|ParsedInt(n)| n).sum()))
+}
+
+#[accepts]
+fn row_sums(#[try_rows(ParseError)] mut grid: Vec<Vec<ParsedInt>>) -> Result<Vec<i64>, ParseError> {
+    grid.retain(|row| !row.is_empty());
+    Ok(grid
+        .iter()
+        .map(|row| row.iter().map(|ParsedInt(n)| n).sum())
+        .collect())
+}
+
+fn main() {
+    assert_eq!(count((1, 2, 3)), 3);
+    assert_eq!(count(vec![4]), 1);
+    assert_eq!(widths(((1, 2), [3, 4, 5])), vec![2, 3]);
+    assert_eq!(sum(("1", "2", "3")).unwrap(), Int(6));
+    assert!(sum(vec!["x"]).is_err());
+    assert_eq!(row_sums((("1", "2"), vec!["3"])).unwrap(), vec![3, 3]);
+}
@@ -2,7 +2,14 @@ use itemize::*;
 
 // tuples(1..=6) - explicit range syntax
 #[derive(IntoItems, IntoRows)]
-#[items_from(types(String, char, &'a str), tuples(1..=6), collections(vec, slice, array))]
+#[items_from(
+    target = Foo<String>,
+    types(String, char, &'a str),
+    tuples(1..=6),
+    collections(vec, slice, array)
+)]
+#[items_from(target = Foo<u64>, types(u64, u32), collections(vec), wrap)]
+#[items_from(target = Foo<std::path::PathBuf>, types(std::path::PathBuf, &'a str), wrap)]
 pub struct Foo<T>(T)
 where
     T: Clone;
@@ -137,6 +144,57 @@ fn check_qux() {
     let _ = into_items((1, 2, 3));
 }
 
+// cloned - borrowed sources clone into the owned `From<String>` impl
+#[derive(IntoItems, TryIntoItems)]
+#[items_from(types(String), tuples(3), collections(vec, slice), cloned)]
+pub struct Name(#[allow(dead_code)] String);
+
+impl From<String> for Name {
+    fn from(value: String) -> Self {
+        Name(value)
+    }
+}
+
+fn check_cloned() {
+    fn into_items(x: impl IntoItems<Name>) -> Vec<Name> {
+        x.into_items().collect()
+    }
+    fn try_into_items(
+        x: impl TryIntoItems<Name, std::convert::Infallible>,
+    ) -> Result<Vec<Name>, std::convert::Infallible> {
+        x.try_into_items().collect()
+    }
+    let names = vec!["a".to_string(), "b".to_string()];
+    let other = "c".to_string();
+    let _ = into_items(&other);
+    let _ = into_items(&names);
+    let _ = into_items(names.as_slice());
+    let _ = into_items((&other, other.clone(), &names[0]));
+    let _ = try_into_items(&names);
+    let _ = try_into_items((&other, other.clone()));
+}
+
+// copied - borrowed sources copy into the owned `From<u32>` impl
+#[derive(IntoItems)]
+#[items_from(types(u32), tuples(2), collections(slice), copied)]
+pub struct Id(#[allow(dead_code)] u32);
+
+impl From<u32> for Id {
+    fn from(value: u32) -> Self {
+        Id(value)
+    }
+}
+
+fn check_copied() {
+    fn into_items(x: impl IntoItems<Id>) -> Vec<Id> {
+        x.into_items().collect()
+    }
+    let ids = vec![1, 2, 3];
+    let _ = into_items(&ids);
+    let _ = into_items(&ids[..2]);
+    let _ = into_items((&ids[0], 4));
+}
+
 fn main() {
     check_into_items();
     check_into_rows();
@@ -144,4 +202,6 @@ fn main() {
     check_try_into_rows();
     check_baz();
     check_qux();
+    check_cloned();
+    check_copied();
 }
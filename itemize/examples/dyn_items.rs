@@ -0,0 +1,65 @@
+// Inputs of different types queued behind the object-safe `Dyn*` traits and converted
+// later, either directly or by passing the boxes on as inputs themselves.
+use itemize::{DynIntoItems, DynIntoRows, DynTryIntoItems, IntoItems, IntoRows, TryIntoItems};
+
+#[derive(Debug, Clone, Copy, PartialEq, IntoItems, IntoRows)]
+#[items_from(types(u32), tuples(3), collections(vec, array), wrap)]
+struct Id(u32);
+
+#[derive(Debug, PartialEq, TryIntoItems)]
+#[items_from(types(&'a str), tuples(2), collections(vec))]
+struct Port(u16);
+
+impl TryFrom<&str> for Port {
+    type Error = std::num::ParseIntError;
+    fn try_from(port: &str) -> Result<Self, Self::Error> {
+        port.parse().map(Port)
+    }
+}
+
+fn ids(input: impl IntoItems<Id>) -> Vec<u32> {
+    input.into_items().map(|Id(id)| id).collect()
+}
+
+fn id_rows(input: impl IntoRows<Id>) -> Vec<Vec<u32>> {
+    input
+        .into_rows()
+        .map(|row| row.map(|Id(id)| id).collect())
+        .collect()
+}
+
+fn ports(
+    input: impl TryIntoItems<Port, std::num::ParseIntError>,
+) -> Result<Vec<Port>, std::num::ParseIntError> {
+    input.try_into_items().collect()
+}
+
+fn main() {
+    let queue: Vec<Box<dyn DynIntoItems<Id>>> = vec![
+        Box::new(1u32),
+        Box::new((2u32, 3u32)),
+        Box::new(vec![4u32, 5]),
+    ];
+    let flattened: Vec<Id> = queue
+        .into_iter()
+        .flat_map(|input| input.into_items_boxed())
+        .collect();
+    assert_eq!(flattened.len(), 5);
+
+    let queued: Box<dyn DynIntoItems<Id>> = Box::new([6u32, 7]);
+    assert_eq!(ids(queued), vec![6, 7]);
+
+    let rows: Box<dyn DynIntoRows<Id>> = Box::new(((1u32, 2u32), vec![3u32]));
+    assert_eq!(id_rows(rows), vec![vec![1, 2], vec![3]]);
+
+    let pending: Vec<Box<dyn DynTryIntoItems<Port, std::num::ParseIntError>>> =
+        vec![Box::new("80"), Box::new(("443", "8080"))];
+    let parsed: Result<Vec<Port>, _> = pending
+        .into_iter()
+        .flat_map(|input| input.try_into_items_boxed())
+        .collect();
+    assert_eq!(parsed, Ok(vec![Port(80), Port(443), Port(8080)]));
+
+    let invalid: Box<dyn DynTryIntoItems<Port, _>> = Box::new(vec!["x"]);
+    assert!(ports(invalid).is_err());
+}
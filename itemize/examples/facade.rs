@@ -0,0 +1,27 @@
+// A facade crate re-exporting itemize, or a renamed dependency, points the
+// generated code at the re-export with `crate = path`.
+mod facade {
+    pub use itemize;
+}
+
+use facade::itemize::{IntoItems, IntoRows};
+
+#[derive(Debug, PartialEq, IntoItems, IntoRows)]
+#[items_from(types(u32), tuples(2), collections(vec), wrap, crate = facade::itemize)]
+struct Count(u32);
+
+fn collect_counts(input: impl IntoItems<Count>) -> Vec<u32> {
+    input.into_items().map(|Count(n)| n).collect()
+}
+
+fn collect_rows(input: impl IntoRows<Count>) -> Vec<Vec<u32>> {
+    input
+        .into_rows()
+        .map(|row| row.map(|Count(n)| n).collect())
+        .collect()
+}
+
+fn main() {
+    assert_eq!(collect_counts((1, 2)), vec![1, 2]);
+    assert_eq!(collect_rows((vec![1, 2], 3)), vec![vec![1, 2], vec![3]]);
+}
@@ -0,0 +1,71 @@
+// `impl_items!` generates impls for a type that cannot carry `#[derive]`, such as one
+// emitted by another macro. Orphan rules still apply: the target must be defined in
+// the crate invoking the macro, or wrapped in a local newtype declared with `as Name`.
+use std::path::PathBuf;
+
+use itemize::{IntoItems, IntoRows, TryIntoItems, impl_items};
+
+macro_rules! id_type {
+    ($name:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct $name(u64);
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                $name(id)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = std::num::ParseIntError;
+            fn try_from(id: &str) -> Result<Self, Self::Error> {
+                id.parse().map($name)
+            }
+        }
+    };
+}
+
+id_type!(UserId);
+
+impl_items!(IntoItems, IntoRows for UserId: types(u64), tuples(3), collections(vec, slice, array));
+impl_items!(TryIntoItems for UserId: types(&'a str), tuples(3), collections(vec), error_type(std::num::ParseIntError));
+
+// `PathBuf` comes from `std`, so the impls go to a newtype converting back into it
+impl_items!(IntoItems for PathBuf as pub Paths: types(PathBuf, &'a str, String), tuples(3), collections(vec));
+
+fn paths(input: impl IntoItems<Paths>) -> Vec<PathBuf> {
+    input.into_items().map(PathBuf::from).collect()
+}
+
+fn ids(input: impl IntoItems<UserId>) -> Vec<u64> {
+    input.into_items().map(|UserId(id)| id).collect()
+}
+
+fn id_rows(input: impl IntoRows<UserId>) -> Vec<Vec<u64>> {
+    input
+        .into_rows()
+        .map(|row| row.map(|UserId(id)| id).collect())
+        .collect()
+}
+
+fn parse_ids(
+    input: impl TryIntoItems<UserId, std::num::ParseIntError>,
+) -> Result<Vec<u64>, std::num::ParseIntError> {
+    input
+        .try_into_items()
+        .map(|id| id.map(|UserId(id)| id))
+        .collect()
+}
+
+fn main() {
+    assert_eq!(ids(1u64), vec![1]);
+    assert_eq!(ids((1u64, 2u64)), vec![1, 2]);
+    assert_eq!(ids(vec![3u64, 4]), vec![3, 4]);
+    assert_eq!(id_rows((vec![1u64], [2u64, 3])), vec![vec![1], vec![2, 3]]);
+    assert_eq!(parse_ids(("5", "6")), Ok(vec![5, 6]));
+    assert!(parse_ids(vec!["x"]).is_err());
+    assert_eq!(
+        paths(("a", "b".to_string())),
+        [PathBuf::from("a"), PathBuf::from("b")]
+    );
+}
@@ -0,0 +1,99 @@
+// `tuples(lean)` links a target to the `LeanTuple` impls compiled once in `itemize` (sizes
+// 1 to 16 with the `tuples-16` feature) instead of generating one impl per size and derive.
+use itemize::{IntoItems, IntoRows, LeanTuple, TryIntoItems, TryIntoRows};
+
+#[derive(Debug, Clone, Copy, PartialEq, IntoItems, IntoRows, TryIntoItems, TryIntoRows)]
+#[items_from(types(u8, u16, u32), tuples(lean), collections(vec, array))]
+struct Id(u32);
+
+impl<T: Into<u32>> From<T> for Id {
+    fn from(id: T) -> Self {
+        Id(id.into())
+    }
+}
+
+#[derive(Debug, PartialEq, TryIntoItems)]
+#[items_from(parse(&'a str), tuples(lean), error_type(std::num::ParseIntError))]
+#[items_from(parse(String), error_type(Box<dyn std::error::Error>))]
+struct Int(i64);
+
+impl std::str::FromStr for Int {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Int)
+    }
+}
+
+// Plain tuples still infer targets generating their own tuple impls.
+#[derive(Debug, PartialEq, IntoItems)]
+#[items_from(types(char), tuples(3), wrap)]
+struct Letter(char);
+
+#[derive(Debug, PartialEq, IntoItems)]
+#[items_from(types(u8), tuples(lean))]
+struct Tagged<T>(T);
+
+impl<T: From<u8>> From<u8> for Tagged<T> {
+    fn from(value: u8) -> Self {
+        Tagged(value.into())
+    }
+}
+
+fn ids(input: impl IntoItems<Id>) -> Vec<u32> {
+    input.into_items().map(|Id(id)| id).collect()
+}
+
+fn id_rows(input: impl IntoRows<Id>) -> Vec<Vec<u32>> {
+    input
+        .into_rows()
+        .map(|row| row.map(|Id(id)| id).collect())
+        .collect()
+}
+
+fn checked_ids(input: impl TryIntoItems<Id, std::convert::Infallible>) -> Vec<u32> {
+    input
+        .try_into_items()
+        .map(|id| id.map(|Id(id)| id).unwrap_or_else(|e| match e {}))
+        .collect()
+}
+
+fn checked_rows(input: impl TryIntoRows<Id, std::convert::Infallible>) -> usize {
+    input.try_into_rows().map(|row| row.count()).sum()
+}
+
+fn ints(
+    input: impl TryIntoItems<Int, std::num::ParseIntError>,
+) -> Result<Vec<Int>, std::num::ParseIntError> {
+    input.try_into_items().collect()
+}
+
+fn main() {
+    assert_eq!(ids(LeanTuple((1u8, 2u16, 3u32))), vec![1, 2, 3]);
+    assert_eq!(
+        ids(LeanTuple((
+            1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8
+        ))),
+        (1..=12).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        id_rows(LeanTuple((vec![1u32], [2u8, 3]))),
+        vec![vec![1], vec![2, 3]]
+    );
+    assert_eq!(checked_ids(LeanTuple((1u8, 2u16))), vec![1, 2]);
+    assert_eq!(checked_rows(LeanTuple((vec![1u8, 2], vec![3u32]))), 3);
+
+    assert_eq!(ints(LeanTuple(("1", "2"))), Ok(vec![Int(1), Int(2)]));
+    assert!(ints(LeanTuple(("1", "x"))).is_err());
+    // The `LeanTuple` impls serve every error type of the target's single sources.
+    let boxed: Result<Vec<Int>, Box<dyn std::error::Error>> =
+        LeanTuple(("3".to_string(), "4".to_string()))
+            .try_into_items()
+            .collect();
+    assert_eq!(boxed.unwrap(), vec![Int(3), Int(4)]);
+
+    let letters: Vec<Letter> = ('a', 'b').into_items().collect();
+    assert_eq!(letters, vec![Letter('a'), Letter('b')]);
+
+    let tagged: Vec<Tagged<u64>> = LeanTuple((1u8, 2u8)).into_items().collect();
+    assert_eq!(tagged, vec![Tagged(1), Tagged(2)]);
+}
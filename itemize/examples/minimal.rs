@@ -1,29 +1,17 @@
 use itemize::{IntoItems, IntoRows};
 
 #[derive(Debug, IntoItems)]
-#[items_from(types(u32), tuples(2), collections(vec, array))]
+#[items_from(types(u32), tuples(2), collections(vec, array), wrap)]
 struct Count(u32);
 
-impl From<u32> for Count {
-    fn from(value: u32) -> Self {
-        Count(value)
-    }
-}
-
 fn collect_counts(input: impl IntoItems<Count>) -> Vec<u32> {
     input.into_items().map(|Count(n)| n).collect()
 }
 
 #[derive(Debug, IntoItems, IntoRows)]
-#[items_from(types(&'a str), tuples(2), collections(vec, array))]
+#[items_from(types(&'a str), tuples(2), collections(vec, array), wrap)]
 struct Cell<'a>(&'a str);
 
-impl<'a> From<&'a str> for Cell<'a> {
-    fn from(value: &'a str) -> Self {
-        Cell(value)
-    }
-}
-
 fn collect_rows<'a>(input: impl IntoRows<Cell<'a>>) -> Vec<Vec<&'a str>> {
     input
         .into_rows()
@@ -1,4 +1,6 @@
-use itemize::TryIntoItems;
+use std::borrow::Cow;
+
+use itemize::{BoxedTryItems, TryIntoBoxedItems, TryIntoItems, TryIntoRows};
 
 #[derive(Debug)]
 struct ParseError(#[allow(dead_code)] String);
@@ -9,25 +11,20 @@ impl From<std::num::ParseIntError> for ParseError {
     }
 }
 
-#[derive(TryIntoItems)]
+#[derive(TryIntoItems, TryIntoRows)]
 #[items_from(
-    types(String, &'a str, i64),
+    types(i64),
+    parse(String, &'a str, Cow<'a, str>),
     tuples(3),
     collections(vec, slice, array),
     error_type(ParseError)
 )]
-struct Int(#[allow(dead_code)] i64);
+#[items_from(parse(&'a str), collections(vec), error_type(Box<dyn std::error::Error>))]
+struct Int(i64);
 
-impl TryFrom<&str> for Int {
-    type Error = std::num::ParseIntError;
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        s.parse::<i64>().map(Int)
-    }
-}
-
-impl TryFrom<String> for Int {
-    type Error = std::num::ParseIntError;
-    fn try_from(s: String) -> Result<Self, Self::Error> {
+impl std::str::FromStr for Int {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         s.parse::<i64>().map(Int)
     }
 }
@@ -43,6 +40,25 @@ fn parse_ints(input: impl TryIntoItems<Int, ParseError>) -> Result<Vec<Int>, Par
     input.try_into_items().collect()
 }
 
+fn parse_rows(input: impl TryIntoRows<Int, ParseError>) -> Result<Vec<Vec<Int>>, ParseError> {
+    input.try_into_rows().map(|row| row.collect()).collect()
+}
+
+fn parse_boxed(
+    input: impl TryIntoItems<Int, Box<dyn std::error::Error>>,
+) -> Result<Vec<Int>, Box<dyn std::error::Error>> {
+    input.try_into_items().collect()
+}
+
+// Erases the input so the summing body is compiled once for every input shape.
+fn sum_ints(input: impl TryIntoItems<Int, ParseError>) -> Result<i64, ParseError> {
+    sum_erased(input.try_into_boxed_items())
+}
+
+fn sum_erased(ints: BoxedTryItems<'_, Int, ParseError>) -> Result<i64, ParseError> {
+    ints.map(|int| int.map(|Int(n)| n)).sum()
+}
+
 fn main() -> Result<(), ParseError> {
     // single value
     let _ = parse_ints("42")?;
@@ -56,5 +72,21 @@ fn main() -> Result<(), ParseError> {
     // vec
     let _ = parse_ints(vec!["100", "200"])?;
 
+    // borrowed or owned through `Cow`
+    let _ = parse_ints(Cow::Borrowed("7"))?;
+
+    // any number of mixed inputs through `items!`, beyond `tuples(3)`
+    let _ = parse_ints(itemize::items!["1", 2, "3".to_string(), ["4", "5"], 6, "7"])?;
+
+    // rows parse every cell
+    let _ = parse_rows((("1", "2"), vec!["3".to_string()]))?;
+
+    // a second `#[items_from]` with its own error type
+    assert!(parse_boxed(vec!["1", "x"]).is_err());
+
+    // boxed items feeding a non-generic body
+    assert_eq!(sum_ints(("1", "2", 3))?, 6);
+    assert_eq!(sum_ints(vec!["4", "5"])?, 9);
+
     Ok(())
 }
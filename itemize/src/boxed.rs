@@ -0,0 +1,162 @@
+use crate::{IntoItems, IntoRows, TryIntoItems};
+
+/// Items of any input erased into a boxed iterator, so code consuming them is compiled
+/// once instead of once per input shape.
+///
+/// Accept `impl IntoItems<T>` in a thin public function and hand the boxed items to a
+/// non-generic inner function holding the actual work:
+///
+/// ```rust
+/// use itemize::{BoxedItems, IntoBoxedItems, IntoItems};
+///
+/// #[derive(IntoItems)]
+/// #[items_from(types(u64), tuples(3), collections(vec), wrap)]
+/// struct Amount(u64);
+///
+/// pub fn total(input: impl IntoItems<Amount>) -> u64 {
+///     total_boxed(input.into_boxed_items())
+/// }
+///
+/// // Monomorphized once, whatever `total` is called with.
+/// fn total_boxed(amounts: BoxedItems<'_, Amount>) -> u64 {
+///     amounts.map(|Amount(n)| n).sum()
+/// }
+///
+/// assert_eq!(total(5), 5);
+/// assert_eq!(total((1, 2, 3)), 6);
+/// assert_eq!(total(vec![4, 5]), 9);
+/// ```
+pub type BoxedItems<'a, T> = Box<dyn Iterator<Item = T> + 'a>;
+
+/// Fallible counterpart of [`BoxedItems`], yielding `Result<T, E>`.
+pub type BoxedTryItems<'a, T, E> = Box<dyn Iterator<Item = Result<T, E>> + 'a>;
+
+/// Erases the iterator of an [`IntoItems`] input into [`BoxedItems`].
+pub trait IntoBoxedItems<T>: IntoItems<T> + Sized {
+    fn into_boxed_items<'a>(self) -> BoxedItems<'a, T>
+    where
+        Self::IntoIter: 'a,
+    {
+        Box::new(self.into_items())
+    }
+}
+
+impl<T, I: IntoItems<T>> IntoBoxedItems<T> for I {}
+
+/// Erases the iterator of a [`TryIntoItems`] input into [`BoxedTryItems`].
+pub trait TryIntoBoxedItems<T, E>: TryIntoItems<T, E> + Sized {
+    fn try_into_boxed_items<'a>(self) -> BoxedTryItems<'a, T, E>
+    where
+        Self::IntoIter: 'a,
+    {
+        Box::new(self.try_into_items())
+    }
+}
+
+impl<T, E, I: TryIntoItems<T, E>> TryIntoBoxedItems<T, E> for I {}
+
+/// Rows of any input erased into boxed iterators, see [`DynIntoRows`].
+pub type BoxedRows<'a, T> = Box<dyn Iterator<Item = BoxedItems<'a, T>> + 'a>;
+
+/// Object-safe counterpart of [`IntoItems`], implemented for every implementor, so
+/// inputs of different types can be queued as `Box<dyn DynIntoItems<T>>` and converted
+/// later.
+///
+/// ```rust
+/// use itemize::{DynIntoItems, IntoItems};
+///
+/// #[derive(Debug, PartialEq, IntoItems)]
+/// #[items_from(types(u32), tuples(2), collections(vec), wrap)]
+/// struct Id(u32);
+///
+/// let queued: Vec<Box<dyn DynIntoItems<Id>>> =
+///     vec![Box::new(1u32), Box::new((2u32, 3u32)), Box::new(vec![4u32])];
+/// let ids: Vec<Id> = queued
+///     .into_iter()
+///     .flat_map(|input| input.into_items_boxed())
+///     .collect();
+/// assert_eq!(ids, vec![Id(1), Id(2), Id(3), Id(4)]);
+/// ```
+pub trait DynIntoItems<T> {
+    fn into_items_boxed<'a>(self: Box<Self>) -> BoxedItems<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+}
+
+impl<T, I: IntoItems<T>> DynIntoItems<T> for I {
+    fn into_items_boxed<'a>(self: Box<Self>) -> BoxedItems<'a, T>
+    where
+        Self: 'a,
+        T: 'a,
+    {
+        Box::new((*self).into_items())
+    }
+}
+
+impl<'a, T: 'a> IntoItems<T> for Box<dyn DynIntoItems<T> + 'a> {
+    type IntoIter = BoxedItems<'a, T>;
+
+    fn into_items(self) -> Self::IntoIter {
+        self.into_items_boxed()
+    }
+}
+
+/// Object-safe counterpart of [`TryIntoItems`], implemented for every implementor.
+pub trait DynTryIntoItems<T, E> {
+    fn try_into_items_boxed<'a>(self: Box<Self>) -> BoxedTryItems<'a, T, E>
+    where
+        Self: 'a,
+        T: 'a,
+        E: 'a;
+}
+
+impl<T, E, I: TryIntoItems<T, E>> DynTryIntoItems<T, E> for I {
+    fn try_into_items_boxed<'a>(self: Box<Self>) -> BoxedTryItems<'a, T, E>
+    where
+        Self: 'a,
+        T: 'a,
+        E: 'a,
+    {
+        Box::new((*self).try_into_items())
+    }
+}
+
+impl<'a, T: 'a, E: 'a> TryIntoItems<T, E> for Box<dyn DynTryIntoItems<T, E> + 'a> {
+    type IntoIter = BoxedTryItems<'a, T, E>;
+
+    fn try_into_items(self) -> Self::IntoIter {
+        self.try_into_items_boxed()
+    }
+}
+
+/// Object-safe counterpart of [`IntoRows`], implemented for every implementor.
+pub trait DynIntoRows<T> {
+    fn into_rows_boxed<'a>(self: Box<Self>) -> BoxedRows<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+}
+
+impl<T, I: IntoRows<T>> DynIntoRows<T> for I {
+    fn into_rows_boxed<'a>(self: Box<Self>) -> BoxedRows<'a, T>
+    where
+        Self: 'a,
+        T: 'a,
+    {
+        Box::new(
+            (*self)
+                .into_rows()
+                .map(|row| Box::new(row) as BoxedItems<'a, T>),
+        )
+    }
+}
+
+impl<'a, T: 'a> IntoRows<T> for Box<dyn DynIntoRows<T> + 'a> {
+    type RowIter = BoxedItems<'a, T>;
+    type Rows = BoxedRows<'a, T>;
+
+    fn into_rows(self) -> Self::Rows {
+        self.into_rows_boxed()
+    }
+}
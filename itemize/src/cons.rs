@@ -0,0 +1,80 @@
+use crate::{IntoItems, TryIntoItems};
+
+/// Heterogeneous list of inputs built by [`items!`](crate::items), chaining the items
+/// of its head with those of its tail.
+///
+/// Unlike tuples, a list has no length limit, so call sites are not bound by the
+/// target's `tuples(...)` configuration. Every element must itself implement
+/// [`IntoItems`] (or [`TryIntoItems`]) for the target.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cons<H, T>(pub H, pub T);
+
+/// End of a [`Cons`] list, yielding no items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Nil;
+
+impl<Item, H, T> IntoItems<Item> for Cons<H, T>
+where
+    H: IntoItems<Item>,
+    T: IntoItems<Item>,
+{
+    type IntoIter = std::iter::Chain<H::IntoIter, T::IntoIter>;
+
+    fn into_items(self) -> Self::IntoIter {
+        self.0.into_items().chain(self.1.into_items())
+    }
+}
+
+impl<Item, E, H, T> TryIntoItems<Item, E> for Cons<H, T>
+where
+    H: TryIntoItems<Item, E>,
+    T: TryIntoItems<Item, E>,
+{
+    type IntoIter = std::iter::Chain<H::IntoIter, T::IntoIter>;
+
+    fn try_into_items(self) -> Self::IntoIter {
+        self.0.try_into_items().chain(self.1.try_into_items())
+    }
+}
+
+impl<Item> IntoItems<Item> for Nil {
+    type IntoIter = std::iter::Empty<Item>;
+
+    fn into_items(self) -> Self::IntoIter {
+        std::iter::empty()
+    }
+}
+
+impl<Item, E> TryIntoItems<Item, E> for Nil {
+    type IntoIter = std::iter::Empty<Result<Item, E>>;
+
+    fn try_into_items(self) -> Self::IntoIter {
+        std::iter::empty()
+    }
+}
+
+/// Builds a [`Cons`] list from any number of inputs, each implementing
+/// [`IntoItems`] (or [`TryIntoItems`]) for the same target.
+///
+/// ```rust
+/// use itemize::{IntoItems, items};
+///
+/// #[derive(Debug, PartialEq, IntoItems)]
+/// #[items_from(types(u32), tuples(2), collections(vec), wrap)]
+/// struct Id(u32);
+///
+/// // More elements than `tuples(2)` allows, mixing single values, tuples and vecs
+/// let ids: Vec<Id> = items![1u32, (2u32, 3u32), vec![4u32, 5], 6u32, 7u32]
+///     .into_items()
+///     .collect();
+/// assert_eq!(ids.len(), 7);
+/// ```
+#[macro_export]
+macro_rules! items {
+    () => {
+        $crate::Nil
+    };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        $crate::Cons($head, $crate::items!($($tail),*))
+    };
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+
+use crate::{IntoItems, IntoRows, TryIntoItems, TryIntoRows};
+
+/// Collection helpers for inputs of [`IntoItems`], checking the number of items where
+/// the plain iterator would leave it to the caller.
+///
+/// Implemented only for types that are inputs of the target, so the method names don't
+/// clash with iterator extensions such as `itertools::Itertools` on ordinary iterators.
+/// [`TryItemsExt`], [`RowsExt`] and [`TryRowsExt`] are the counterparts for the other
+/// traits.
+///
+/// ```rust
+/// use itemize::{IntoItems, IntoRows, ItemsExt, RowsExt, ShapeError};
+///
+/// #[derive(Debug, PartialEq, IntoItems, IntoRows)]
+/// #[items_from(types(u32), tuples(3), collections(vec, array), wrap)]
+/// struct Id(u32);
+///
+/// fn primary(input: impl IntoItems<Id>) -> Result<Id, ShapeError> {
+///     input.exactly_one()
+/// }
+///
+/// fn grid(input: impl IntoRows<Id>) -> Result<Vec<Vec<Id>>, ShapeError> {
+///     input.collect_matrix()
+/// }
+///
+/// assert_eq!(primary(7), Ok(Id(7)));
+/// assert_eq!(
+///     primary((1, 2)).unwrap_err().to_string(),
+///     "expected exactly 1 item, found 2"
+/// );
+/// assert!(grid(([1, 2], vec![3, 4])).is_ok());
+/// assert_eq!(
+///     grid(([1, 2], vec![3])).unwrap_err().to_string(),
+///     "row 1 has 1 item, expected 2 like the first row"
+/// );
+/// ```
+pub trait ItemsExt<T>: IntoItems<T> + Sized {
+    /// Collects the items into a `Vec`.
+    fn collect_vec(self) -> Vec<T> {
+        self.into_items().collect()
+    }
+
+    /// The only item of the input, or [`ShapeError::Count`] if it has none or several.
+    fn exactly_one(self) -> Result<T, ShapeError> {
+        let [item] = self.collect_array()?;
+        Ok(item)
+    }
+
+    /// The item of the input if any, or [`ShapeError::TooMany`] if it has several.
+    fn at_most_one(self) -> Result<Option<T>, ShapeError> {
+        let mut items = self.into_items();
+        let first = items.next();
+        match items.count() {
+            0 => Ok(first),
+            rest => Err(ShapeError::TooMany {
+                max: 1,
+                found: rest + 1,
+            }),
+        }
+    }
+
+    /// Exactly `N` items as an array, or [`ShapeError::Count`] for any other number.
+    fn collect_array<const N: usize>(self) -> Result<[T; N], ShapeError> {
+        let items: Vec<T> = self.into_items().collect();
+        let found = items.len();
+        items
+            .try_into()
+            .map_err(|_| ShapeError::Count { expected: N, found })
+    }
+}
+
+impl<T, I: IntoItems<T>> ItemsExt<T> for I {}
+
+/// Collection helpers for inputs of [`TryIntoItems`].
+pub trait TryItemsExt<T, E>: TryIntoItems<T, E> + Sized {
+    /// Collects the items into a `Vec`, stopping at the first conversion error.
+    fn try_collect_vec(self) -> Result<Vec<T>, E> {
+        self.try_into_items().collect()
+    }
+}
+
+impl<T, E, I: TryIntoItems<T, E>> TryItemsExt<T, E> for I {}
+
+/// Collection helpers for inputs of [`IntoRows`].
+pub trait RowsExt<T>: IntoRows<T> + Sized {
+    /// Collects every row, or [`ShapeError::Ragged`] if a row's length differs from the
+    /// first row's.
+    fn collect_matrix(self) -> Result<Vec<Vec<T>>, ShapeError> {
+        let rows: Vec<Vec<T>> = self.into_rows().map(|row| row.collect()).collect();
+        let expected = rows.first().map_or(0, Vec::len);
+        match rows
+            .iter()
+            .map(Vec::len)
+            .enumerate()
+            .find(|&(_, len)| len != expected)
+        {
+            Some((row, found)) => Err(ShapeError::Ragged {
+                row,
+                expected,
+                found,
+            }),
+            None => Ok(rows),
+        }
+    }
+}
+
+impl<T, I: IntoRows<T>> RowsExt<T> for I {}
+
+/// Collection helpers for inputs of [`TryIntoRows`].
+pub trait TryRowsExt<T, E>: TryIntoRows<T, E> + Sized {
+    /// Collects every row, stopping at the first conversion error.
+    fn try_collect_rows(self) -> Result<Vec<Vec<T>>, E> {
+        self.try_into_rows().map(|row| row.collect()).collect()
+    }
+}
+
+impl<T, E, I: TryIntoRows<T, E>> TryRowsExt<T, E> for I {}
+
+/// Input whose items or rows don't have the shape an [`ItemsExt`] or [`RowsExt`] helper
+/// requires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+    /// The input has `found` items instead of exactly `expected`.
+    Count { expected: usize, found: usize },
+    /// The input has `found` items, more than `max`.
+    TooMany { max: usize, found: usize },
+    /// Row `row` has `found` items while the first row has `expected`.
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items = |n: usize| if n == 1 { "item" } else { "items" };
+        match *self {
+            ShapeError::Count { expected, found } => write!(
+                f,
+                "expected exactly {expected} {}, found {found}",
+                items(expected)
+            ),
+            ShapeError::TooMany { max, found } => {
+                write!(f, "expected at most {max} {}, found {found}", items(max))
+            }
+            ShapeError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {row} has {found} {}, expected {expected} like the first row",
+                items(found)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ShapeError {}
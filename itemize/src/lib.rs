@@ -16,10 +16,80 @@
 //!
 //! Generate trait implementations with the `#[items_from(...)]` attribute:
 //!
-//! - `types(...)` – types to accept (e.g., `String`, `&'a str`, `usize`)
+//! - `types(...)` – types to accept (e.g., `String`, `&'a str`, `usize`); `&'a str via String` converts through an intermediate type
+//!   (tuple and collection elements of such a target then each need to be a declared source or the target itself),
+//!   and `for<T: Display> Option<T>` accepts a generic source, adding its parameters to the impl
 //! - `tuples(n)` – support tuples up to size n
+//! - `tuples(lean)` – accept tuples wrapped in [`LeanTuple`], whose impls are compiled once in `itemize`
+//!   (sizes 1 to 8 or 1 to 16 with the `tuples-8` or `tuples-16` feature), instead of generating one
+//!   impl per size; plain tuples are unaffected and may still use a `tuples(n)` range
 //! - `collections(vec, slice, array)` – which collection types to support
-//! - `error_type(Type)` – lock `TryInto*` impls to a specific error type
+//! - `error_type(Type)` – lock `TryInto*` impls to a specific error type; repeat
+//!   `#[items_from]` with a different `error_type` to generate impls for each
+//! - `parse(...)` – string sources converted through the target's `FromStr` in the `TryInto*` impls
+//! - `wrap` – wrap each declared type into the field of a single-field struct: `IntoItems` generates
+//!   `From<Source>` through the field's `From<Source>`, `TryIntoItems` converts through its `TryFrom<Source>`
+//! - `crate = path` – path to `itemize` in the generated code, for re-exports or renamed dependencies
+//! - `target = Foo<String>` – generate for one instantiation of a generic target instead of
+//!   every `Foo<T>`; repeat `#[items_from]` with other targets and their own sources
+//! - `cloned` / `copied` – accept borrowed sources (`&[T]`, `&Vec<T>`, `&T`) through the owned `From<T>`/`TryFrom<T>` impls
+//! - `items(...)` / `rows(...)` – per-trait sections overriding `types`, `tuples`, `collections`,
+//!   `error_type` and `cloned`/`copied` for `IntoItems`/`TryIntoItems` or `IntoRows`/`TryIntoRows`,
+//!   e.g. `items(tuples(12)), rows(tuples(3), collections(vec))`
+//!
+//! On enums, `types(...)` may also be placed on single-field variants. `IntoItems` then generates
+//! `From<Source>` into the matching variant and `TryIntoItems` converts through the field's
+//! `TryFrom<Source>`, so a heterogeneous input like `(1, "a", 2.5)` flattens into one stream of the enum.
+//!
+//! For a type that cannot carry a derive, such as one emitted by another macro, `impl_items!`
+//! takes the traits, the target and the same options:
+//!
+//! ```rust
+//! # use itemize::{IntoItems, impl_items};
+//! # #[derive(Debug, PartialEq)]
+//! # struct UserId(u64);
+//! # impl From<u64> for UserId {
+//! #     fn from(id: u64) -> Self { UserId(id) }
+//! # }
+//! impl_items!(IntoItems for UserId: types(u64), tuples(4), collections(vec, slice));
+//!
+//! let ids: Vec<UserId> = (1u64, 2u64).into_items().collect();
+//! assert_eq!(ids, vec![UserId(1), UserId(2)]);
+//! ```
+//!
+//! Generic targets declare their parameters after `for`, as in
+//! `impl_items!(IntoItems for<T: Clone> Tagged<T>: ...)`. Orphan rules still require the target
+//! to be defined in the invoking crate; for a type from another crate, `as Name` declares a local
+//! newtype wrapping it instead, with `From` for each declared source and back into the target:
+//!
+//! ```rust
+//! # use itemize::{IntoItems, impl_items};
+//! # use std::path::PathBuf;
+//! impl_items!(IntoItems for PathBuf as #[derive(Debug)] pub Paths: types(&'a str, String), collections(vec));
+//!
+//! fn paths(input: impl IntoItems<Paths>) -> Vec<PathBuf> {
+//!     input.into_items().map(PathBuf::from).collect()
+//! }
+//!
+//! assert_eq!(paths(vec!["a", "b"]), [PathBuf::from("a"), PathBuf::from("b")]);
+//! ```
+//!
+//! `#[accepts]` on a function rewrites parameters marked `#[items]`, `#[rows]`, `#[try_items(E)]`
+//! or `#[try_rows(E)]` into the matching `impl` bound and collects them back into the declared
+//! collection at the start of the body, propagating errors of the fallible traits with `?`:
+//!
+//! ```rust
+//! # use itemize::{IntoItems, accepts};
+//! # #[derive(IntoItems)]
+//! # #[items_from(types(u32), tuples(3), collections(vec), wrap)]
+//! # struct Id(u32);
+//! #[accepts]
+//! fn count(#[items] ids: Vec<Id>) -> usize {
+//!     ids.len()
+//! }
+//!
+//! assert_eq!(count((1, 2, 3)), 3);
+//! ```
 //!
 //! # Examples
 //!
@@ -29,15 +99,9 @@
 //! use itemize::IntoItems;
 //!
 //! #[derive(Debug, IntoItems)]
-//! #[items_from(types(u32), tuples(2), collections(vec, array))]
+//! #[items_from(types(u32), tuples(2), collections(vec, array), wrap)]
 //! struct Count(u32);
 //!
-//! impl From<u32> for Count {
-//!     fn from(value: u32) -> Self {
-//!         Count(value)
-//!     }
-//! }
-//!
 //! fn collect_counts(input: impl IntoItems<Count>) -> Vec<u32> {
 //!     input.into_items().map(|Count(n)| n).collect()
 //! }
@@ -64,19 +128,12 @@
 //! }
 //!
 //! #[derive(TryIntoItems)]
-//! #[items_from(types(String, &'a str, i64), tuples(3), collections(vec, slice, array), error_type(ParseError))]
+//! #[items_from(types(i64), parse(String, &'a str), tuples(3), collections(vec, slice, array), error_type(ParseError))]
 //! struct Int(i64);
 //!
-//! impl TryFrom<&str> for Int {
-//!     type Error = std::num::ParseIntError;
-//!     fn try_from(s: &str) -> Result<Self, Self::Error> {
-//!         s.parse::<i64>().map(Int)
-//!     }
-//! }
-//!
-//! impl TryFrom<String> for Int {
-//!     type Error = std::num::ParseIntError;
-//!     fn try_from(s: String) -> Result<Self, Self::Error> {
+//! impl std::str::FromStr for Int {
+//!     type Err = std::num::ParseIntError;
+//!     fn from_str(s: &str) -> Result<Self, Self::Err> {
 //!         s.parse::<i64>().map(Int)
 //!     }
 //! }
@@ -107,15 +164,9 @@
 //! use itemize::{IntoItems, IntoRows};
 //!
 //! #[derive(Debug, IntoItems, IntoRows)]
-//! #[items_from(types(&'a str), tuples(3))]
+//! #[items_from(types(&'a str), tuples(3), wrap)]
 //! struct Cell<'a>(&'a str);
 //!
-//! impl<'a> From<&'a str> for Cell<'a> {
-//!     fn from(value: &'a str) -> Self {
-//!         Cell(value)
-//!     }
-//! }
-//!
 //! fn collect_rows<'a>(input: impl IntoRows<Cell<'a>>) -> Vec<Vec<&'a str>> {
 //!     input
 //!         .into_rows()
@@ -137,29 +188,90 @@
 //!
 //! The [`Either`] type enables composing heterogeneous iterators through nesting.
 //! It allows two different iterator types to be unified into a single type.
+//!
+//! The [`items!`] macro builds a [`Cons`] list from any number of inputs, each
+//! implementing `IntoItems<T>` (or `TryIntoItems<T, E>`) on its own, so mixed argument
+//! lists are not limited by the target's `tuples(...)` range.
+//!
+//! [`BoxedItems`] and [`BoxedTryItems`] erase the iterator of any input through
+//! [`IntoBoxedItems::into_boxed_items`] and [`TryIntoBoxedItems::try_into_boxed_items`], so a
+//! function taking `impl IntoItems<T>` can pass its items on to a non-generic body that is
+//! compiled once instead of once per input shape.
+//!
+//! [`DynIntoItems`], [`DynTryIntoItems`] and [`DynIntoRows`] are object-safe counterparts of the
+//! traits, implemented for every implementor, so inputs of different types can be queued as
+//! `Box<dyn DynIntoItems<T>>` and converted later. Such boxes are themselves accepted as inputs.
+//!
+//! [`ItemsExt`] and [`RowsExt`] add collection helpers to inputs that check what a function
+//! expects of them: [`exactly_one`](ItemsExt::exactly_one), [`at_most_one`](ItemsExt::at_most_one)
+//! and [`collect_array`](ItemsExt::collect_array) the number of items,
+//! [`collect_matrix`](RowsExt::collect_matrix) that all rows have the same length, each failing
+//! with a [`ShapeError`] describing the mismatch. [`TryItemsExt`] and [`TryRowsExt`] collect the
+//! fallible traits.
 
 #[cfg(feature = "derive")]
 pub use itemize_derive::*;
 
+pub mod boxed;
+pub use boxed::{
+    BoxedItems, BoxedRows, BoxedTryItems, DynIntoItems, DynIntoRows, DynTryIntoItems,
+    IntoBoxedItems, TryIntoBoxedItems,
+};
+
+pub mod cons;
+pub use cons::{Cons, Nil};
+
 pub mod either;
 pub use either::Either;
 
+pub mod ext;
+pub use ext::{ItemsExt, RowsExt, ShapeError, TryItemsExt, TryRowsExt};
+
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+
+mod tuples;
+pub use tuples::LeanTuple;
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not accepted as items of `{Item}`",
+    label = "not an accepted input for `{Item}`",
+    note = "`{Item}` accepts the types listed in `#[items_from(types(...))]`, tuples within `tuples(...)` of accepted types, and `Vec`, slice or array inputs enabled by `collections(vec, slice, array)`",
+    note = "borrowed sources such as `&T` or `&Vec<T>` need `cloned` or `copied`; `items![...]` accepts mixed inputs of any length"
+)]
 pub trait IntoItems<Item> {
     type IntoIter: ::std::iter::Iterator<Item = Item>;
     fn into_items(self) -> Self::IntoIter;
 }
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not accepted as fallible items of `{Item}` with error `{E}`",
+    label = "not an accepted input for `{Item}`",
+    note = "`{Item}` accepts the types listed in `#[items_from(types(...))]` or `parse(...)`, tuples within `tuples(...)` of accepted types, and `Vec`, slice or array inputs enabled by `collections(vec, slice, array)`",
+    note = "with `error_type(...)` the impls exist only for that error type; otherwise each source's error must convert into `{E}`"
+)]
 pub trait TryIntoItems<Item, E> {
     type IntoIter: ::std::iter::Iterator<Item = Result<Item, E>>;
     fn try_into_items(self) -> Self::IntoIter;
 }
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not accepted as rows of `{Row}`",
+    label = "not an accepted input for rows of `{Row}`",
+    note = "rows of `{Row}` are tuples within `tuples(...)`, or `Vec`s or arrays enabled by `collections(vec, array)`, whose elements each implement `IntoItems<{Row}>`"
+)]
 pub trait IntoRows<Row> {
     type RowIter: ::std::iter::Iterator<Item = Row>;
     type Rows: ::std::iter::Iterator<Item = Self::RowIter>;
     fn into_rows(self) -> Self::Rows;
 }
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not accepted as fallible rows of `{Row}` with error `{E}`",
+    label = "not an accepted input for rows of `{Row}`",
+    note = "rows of `{Row}` are tuples within `tuples(...)`, or `Vec`s or arrays enabled by `collections(vec, array)`, whose elements each implement `TryIntoItems<{Row}, {E}>`"
+)]
 pub trait TryIntoRows<Row, E> {
     type RowIter: ::std::iter::Iterator<Item = Result<Row, E>>;
     type Rows: ::std::iter::Iterator<Item = Self::RowIter>;
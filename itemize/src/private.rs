@@ -0,0 +1,177 @@
+//! Helpers called by the generated impls, shared instead of emitted into every body.
+//! Not part of the public API.
+
+/// Converts one element of a collection or tuple through `From`.
+#[inline]
+pub fn from<Target, Item>(item: Item) -> Target
+where
+    Target: ::core::convert::From<Item>,
+{
+    <Target as ::core::convert::From<Item>>::from(item)
+}
+
+/// Converts one element of a collection or tuple through `TryFrom`, converting the
+/// error into `E`.
+#[inline]
+pub fn try_from<Target, Item, E>(item: Item) -> ::core::result::Result<Target, E>
+where
+    Target: ::core::convert::TryFrom<Item>,
+    <Target as ::core::convert::TryFrom<Item>>::Error: ::core::convert::Into<E>,
+{
+    <Target as ::core::convert::TryFrom<Item>>::try_from(item).map_err(::core::convert::Into::into)
+}
+
+/// Parses a `parse(...)` source through the target's `FromStr`, converting the error
+/// into `E`.
+#[inline]
+pub fn parse<Target, Source, E>(source: Source) -> ::core::result::Result<Target, E>
+where
+    Source: ::core::convert::AsRef<::core::primitive::str>,
+    Target: ::core::str::FromStr,
+    <Target as ::core::str::FromStr>::Err: ::core::convert::Into<E>,
+{
+    <Target as ::core::str::FromStr>::from_str(source.as_ref()).map_err(::core::convert::Into::into)
+}
+
+pub use crate::tuples::{
+    Convert, TryTupleRows, TupleItems, TupleRows, ViaFrom, ViaItems, ViaTryFrom, ViaTryItems,
+};
+
+/// The only item of a single source, converted through its own `IntoItems` impl.
+#[inline]
+pub fn single<Target, Item>(item: Item) -> Target
+where
+    Item: crate::IntoItems<Target, IntoIter = ::core::iter::Once<Target>>,
+{
+    match crate::IntoItems::into_items(item).next() {
+        ::core::option::Option::Some(target) => target,
+        ::core::option::Option::None => unreachable!("`Once` yields one item"),
+    }
+}
+
+/// The only item of a single source, converted through its own `TryIntoItems` impl.
+#[inline]
+pub fn try_single<Target, Item, E>(item: Item) -> ::core::result::Result<Target, E>
+where
+    Item: crate::TryIntoItems<
+            Target,
+            E,
+            IntoIter = ::core::iter::Once<::core::result::Result<Target, E>>,
+        >,
+{
+    match crate::TryIntoItems::try_into_items(item).next() {
+        ::core::option::Option::Some(target) => target,
+        ::core::option::Option::None => unreachable!("`Once` yields one item"),
+    }
+}
+
+/// Supertrait of the `tuples(lean)` markers, implemented for every type once a
+/// `tuples-*` feature provides the `LeanTuple` impls.
+#[diagnostic::on_unimplemented(
+    message = "`tuples(lean)` requires the `tuples-8` or `tuples-16` feature of `itemize`",
+    label = "the `LeanTuple` impls are not compiled in"
+)]
+pub trait LeanTuples {}
+
+#[cfg(feature = "tuples-8")]
+impl<T: ?Sized> LeanTuples for T {}
+
+/// Marker opting a target into the `LeanTuple` impls of `IntoItems`, emitted by
+/// `tuples(lean)`.
+pub trait LeanItems: LeanTuples {}
+
+/// Marker opting a target into the `LeanTuple` impls of `TryIntoItems`, emitted by
+/// `tuples(lean)`. The impls are generic over the error type, so one marker covers the
+/// target's every `error_type`.
+pub trait LeanTryItems: LeanTuples {}
+
+/// Marker opting a target into the `LeanTuple` impls of `IntoRows`, emitted by
+/// `tuples(lean)`.
+pub trait LeanRows: LeanTuples {}
+
+/// Marker opting a target into the `LeanTuple` impls of `TryIntoRows`, emitted by
+/// `tuples(lean)`.
+pub trait LeanTryRows: LeanTuples {}
+
+/// Value of the inherent const through which a derive tells the other derives of its
+/// target that it is present, e.g. `__ITEMIZE_TRY_INTO_ITEMS`. Emitted while an option
+/// only some traits read is set.
+pub struct Derived;
+
+/// Value of those consts through [`Evidence`] when the derive is absent.
+pub struct NotDerived;
+
+/// Falls back to [`NotDerived`] for every derive a type lacks; the inherent const
+/// emitted by a present derive takes precedence.
+pub trait Evidence {
+    const __ITEMIZE_INTO_ITEMS: NotDerived = NotDerived;
+    const __ITEMIZE_TRY_INTO_ITEMS: NotDerived = NotDerived;
+    const __ITEMIZE_INTO_ROWS: NotDerived = NotDerived;
+    const __ITEMIZE_TRY_INTO_ROWS: NotDerived = NotDerived;
+}
+
+impl<T: ?::core::marker::Sized> Evidence for T {}
+
+/// [`Derived`] if either element of a pair of evidence is.
+pub trait EitherDerived {
+    type Output;
+}
+
+impl<B> EitherDerived for (Derived, B) {
+    type Output = Derived;
+}
+
+impl<B> EitherDerived for (NotDerived, B) {
+    type Output = B;
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`error_type` has no effect on the derived traits",
+    label = "no derive reads this `error_type`",
+    note = "`error_type` is read by `TryIntoItems` and `TryIntoRows`; inside `items(...)` or `rows(...)`, only by the one of them reading that section"
+)]
+pub trait ErrorTypeUsed {}
+
+impl ErrorTypeUsed for Derived {}
+
+#[diagnostic::on_unimplemented(
+    message = "`wrap` has no effect on the derived traits",
+    label = "no derive reads this `wrap`",
+    note = "`wrap` is read by `IntoItems` and `TryIntoItems`"
+)]
+pub trait WrapUsed {}
+
+impl WrapUsed for Derived {}
+
+#[diagnostic::on_unimplemented(
+    message = "`parse` has no effect on the derived traits",
+    label = "no derive reads this `parse`",
+    note = "`parse` is read by `TryIntoItems`"
+)]
+pub trait ParseUsed {}
+
+impl ParseUsed for Derived {}
+
+/// Requires a derive reading `error_type` among the pair of evidence.
+pub fn error_type_used<A, B>(_: (A, B))
+where
+    (A, B): EitherDerived,
+    <(A, B) as EitherDerived>::Output: ErrorTypeUsed,
+{
+}
+
+/// Requires a derive reading `wrap` among the pair of evidence.
+pub fn wrap_used<A, B>(_: (A, B))
+where
+    (A, B): EitherDerived,
+    <(A, B) as EitherDerived>::Output: WrapUsed,
+{
+}
+
+/// Requires a derive reading `parse` among the pair of evidence.
+pub fn parse_used<A, B>(_: (A, B))
+where
+    (A, B): EitherDerived,
+    <(A, B) as EitherDerived>::Output: ParseUsed,
+{
+}
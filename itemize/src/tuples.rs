@@ -0,0 +1,280 @@
+//! Per-size tuple conversions called by the tuple impls of the derives, so each impl's
+//! body is a single call instead of its own destructuring and array construction.
+//!
+//! The impls of [`LeanTuple`] shared by every target declaring `tuples(lean)` are built
+//! on the same conversions: the `tuples-8` feature provides sizes 1 to 8 and `tuples-16`
+//! sizes 1 to 16.
+
+use std::marker::PhantomData;
+
+use crate::{Either, IntoItems, TryIntoItems};
+
+/// Conversion of each element of a tuple input into an item of its target.
+pub trait Convert<A> {
+    type Output;
+    fn convert(value: A) -> Self::Output;
+}
+
+/// Converts elements through the target's `From`.
+pub struct ViaFrom<T>(PhantomData<T>);
+
+impl<T: From<A>, A> Convert<A> for ViaFrom<T> {
+    type Output = T;
+
+    #[inline]
+    fn convert(value: A) -> T {
+        T::from(value)
+    }
+}
+
+/// Converts elements through the target's `TryFrom`, converting the error into `E`.
+pub struct ViaTryFrom<T, E>(PhantomData<(T, E)>);
+
+impl<T, E, A> Convert<A> for ViaTryFrom<T, E>
+where
+    T: TryFrom<A>,
+    T::Error: Into<E>,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    fn convert(value: A) -> Result<T, E> {
+        T::try_from(value).map_err(Into::into)
+    }
+}
+
+/// Converts elements that are single sources through their own `IntoItems` impl.
+pub struct ViaItems<T>(PhantomData<T>);
+
+impl<T, A> Convert<A> for ViaItems<T>
+where
+    A: IntoItems<T, IntoIter = std::iter::Once<T>>,
+{
+    type Output = T;
+
+    #[inline]
+    fn convert(value: A) -> T {
+        crate::__private::single(value)
+    }
+}
+
+/// Converts elements that are single sources through their own `TryIntoItems` impl.
+pub struct ViaTryItems<T, E>(PhantomData<(T, E)>);
+
+impl<T, E, A> Convert<A> for ViaTryItems<T, E>
+where
+    A: TryIntoItems<T, E, IntoIter = std::iter::Once<Result<T, E>>>,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    fn convert(value: A) -> Result<T, E> {
+        crate::__private::try_single(value)
+    }
+}
+
+/// Tuples whose elements all convert into the same item through `C`.
+pub trait TupleItems<C> {
+    type Items: Iterator;
+    fn tuple_items(self) -> Self::Items;
+}
+
+/// Tuples whose elements are each a row of `T`.
+pub trait TupleRows<T> {
+    type RowIter: Iterator<Item = T>;
+    type Rows: Iterator<Item = Self::RowIter>;
+    fn tuple_rows(self) -> Self::Rows;
+}
+
+/// Tuples whose elements are each a fallible row of `T`.
+pub trait TryTupleRows<T, E> {
+    type RowIter: Iterator<Item = Result<T, E>>;
+    type Rows: Iterator<Item = Self::RowIter>;
+    fn try_tuple_rows(self) -> Self::Rows;
+}
+
+/// Nested [`Either`] over the row iterator types of a tuple.
+macro_rules! either_type {
+    ($last:ty) => { $last };
+    ($head:ty, $($tail:ty),+) => { Either<$head, either_type!($($tail),+)> };
+}
+
+/// Array of rows, each wrapped into its position in the nested [`Either`].
+macro_rules! either_rows {
+    (@wrap [] $value:expr) => { $value };
+    (@wrap [R $($depth:tt)*] $value:expr) => {
+        Either::Right(either_rows!(@wrap [$($depth)*] $value))
+    };
+    (@rows [$($rows:expr,)*] [$($depth:tt)*] $last:expr) => {
+        [$($rows,)* either_rows!(@wrap [$($depth)*] $last)]
+    };
+    (@rows [$($rows:expr,)*] [$($depth:tt)*] $head:expr, $($tail:expr),+) => {
+        either_rows!(
+            @rows [$($rows,)* either_rows!(@wrap [$($depth)*] Either::Left($head)),]
+            [$($depth)* R] $($tail),+
+        )
+    };
+    ($($value:expr),+) => { either_rows!(@rows [] [] $($value),+) };
+}
+
+macro_rules! tuple_conversions {
+    ($len:literal: $($name:ident $value:ident),+) => {
+        impl<C, O, $($name),+> TupleItems<C> for ($($name,)+)
+        where
+            $(C: Convert<$name, Output = O>,)+
+        {
+            type Items = ::core::array::IntoIter<O, $len>;
+
+            #[inline]
+            fn tuple_items(self) -> Self::Items {
+                let ($($value,)+) = self;
+                [$(<C as Convert<$name>>::convert($value)),+].into_iter()
+            }
+        }
+
+        impl<T, $($name),+> TupleRows<T> for ($($name,)+)
+        where
+            $($name: IntoItems<T>,)+
+        {
+            type RowIter = either_type!($(<$name as IntoItems<T>>::IntoIter),+);
+            type Rows = ::core::array::IntoIter<Self::RowIter, $len>;
+
+            #[inline]
+            fn tuple_rows(self) -> Self::Rows {
+                let ($($value,)+) = self;
+                either_rows!($($value.into_items()),+).into_iter()
+            }
+        }
+
+        impl<T, E, $($name),+> TryTupleRows<T, E> for ($($name,)+)
+        where
+            $($name: TryIntoItems<T, E>,)+
+        {
+            type RowIter = either_type!($(<$name as TryIntoItems<T, E>>::IntoIter),+);
+            type Rows = ::core::array::IntoIter<Self::RowIter, $len>;
+
+            #[inline]
+            fn try_tuple_rows(self) -> Self::Rows {
+                let ($($value,)+) = self;
+                either_rows!($($value.try_into_items()),+).into_iter()
+            }
+        }
+    };
+}
+
+tuple_conversions!(1: A0 a0);
+tuple_conversions!(2: A0 a0, A1 a1);
+tuple_conversions!(3: A0 a0, A1 a1, A2 a2);
+tuple_conversions!(4: A0 a0, A1 a1, A2 a2, A3 a3);
+tuple_conversions!(5: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4);
+tuple_conversions!(6: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5);
+tuple_conversions!(7: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6);
+tuple_conversions!(8: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7);
+tuple_conversions!(9: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8);
+tuple_conversions!(10: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9);
+tuple_conversions!(11: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10);
+tuple_conversions!(12: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11);
+tuple_conversions!(13: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11, A12 a12);
+tuple_conversions!(14: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11, A12 a12, A13 a13);
+tuple_conversions!(15: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11, A12 a12, A13 a13, A14 a14);
+tuple_conversions!(16: A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11, A12 a12, A13 a13, A14 a14, A15 a15);
+
+/// Tuple input of a target declaring `tuples(lean)`, taken by the tuple impls compiled
+/// once here instead of impls generated for every target.
+///
+/// Plain tuples keep only the impls derived for their targets, so they still infer the
+/// target from the only one accepting them. The shared impls need the `tuples-8` feature
+/// for sizes 1 to 8 or `tuples-16` for sizes 1 to 16, and every element must be a single
+/// source accepted by the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LeanTuple<T>(pub T);
+
+/// The impls of [`LeanTuple`], for sizes 1 to 8 with `tuples-8` and up to 16 with
+/// `tuples-16`.
+#[cfg(feature = "tuples-8")]
+mod lean {
+    use super::{LeanTuple, TryTupleRows, TupleItems, TupleRows, ViaItems, ViaTryItems};
+    use crate::__private::{LeanItems, LeanRows, LeanTryItems, LeanTryRows};
+    use crate::{IntoItems, IntoRows, TryIntoItems, TryIntoRows};
+
+    macro_rules! tuple_impls {
+        ($len:literal: $($name:ident),+) => {
+            impl<Item, $($name),+> IntoItems<Item> for LeanTuple<($($name,)+)>
+            where
+                Item: LeanItems,
+                $($name: IntoItems<Item, IntoIter = ::core::iter::Once<Item>>,)+
+            {
+                type IntoIter = ::core::array::IntoIter<Item, $len>;
+
+                #[inline]
+                fn into_items(self) -> Self::IntoIter {
+                    TupleItems::<ViaItems<Item>>::tuple_items(self.0)
+                }
+            }
+
+            impl<Item, E, $($name),+> TryIntoItems<Item, E> for LeanTuple<($($name,)+)>
+            where
+                Item: LeanTryItems,
+                $($name: TryIntoItems<Item, E, IntoIter = ::core::iter::Once<Result<Item, E>>>,)+
+            {
+                type IntoIter = ::core::array::IntoIter<Result<Item, E>, $len>;
+
+                #[inline]
+                fn try_into_items(self) -> Self::IntoIter {
+                    TupleItems::<ViaTryItems<Item, E>>::tuple_items(self.0)
+                }
+            }
+
+            impl<Item, $($name),+> IntoRows<Item> for LeanTuple<($($name,)+)>
+            where
+                Item: LeanRows,
+                $($name: IntoItems<Item>,)+
+            {
+                type RowIter = <($($name,)+) as TupleRows<Item>>::RowIter;
+                type Rows = ::core::array::IntoIter<Self::RowIter, $len>;
+
+                #[inline]
+                fn into_rows(self) -> Self::Rows {
+                    self.0.tuple_rows()
+                }
+            }
+
+            impl<Item, E, $($name),+> TryIntoRows<Item, E> for LeanTuple<($($name,)+)>
+            where
+                Item: LeanTryRows,
+                $($name: TryIntoItems<Item, E>,)+
+            {
+                type RowIter = <($($name,)+) as TryTupleRows<Item, E>>::RowIter;
+                type Rows = ::core::array::IntoIter<Self::RowIter, $len>;
+
+                #[inline]
+                fn try_into_rows(self) -> Self::Rows {
+                    self.0.try_tuple_rows()
+                }
+            }
+        };
+    }
+
+    tuple_impls!(1: A0);
+    tuple_impls!(2: A0, A1);
+    tuple_impls!(3: A0, A1, A2);
+    tuple_impls!(4: A0, A1, A2, A3);
+    tuple_impls!(5: A0, A1, A2, A3, A4);
+    tuple_impls!(6: A0, A1, A2, A3, A4, A5);
+    tuple_impls!(7: A0, A1, A2, A3, A4, A5, A6);
+    tuple_impls!(8: A0, A1, A2, A3, A4, A5, A6, A7);
+
+    #[cfg(feature = "tuples-16")]
+    mod wide {
+        use super::*;
+
+        tuple_impls!(9: A0, A1, A2, A3, A4, A5, A6, A7, A8);
+        tuple_impls!(10: A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+        tuple_impls!(11: A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+        tuple_impls!(12: A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+        tuple_impls!(13: A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+        tuple_impls!(14: A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13);
+        tuple_impls!(15: A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14);
+        tuple_impls!(16: A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15);
+    }
+}
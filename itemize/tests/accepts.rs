@@ -0,0 +1,148 @@
+//! Parameters rewritten by `#[accepts]`, called with each kind of input.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use itemize::{IntoItems, IntoRows, TryIntoItems, TryIntoRows, accepts};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, IntoItems, IntoRows)]
+#[items_from(types(u32), tuples(3), collections(vec, array), wrap)]
+struct Id(u32);
+
+#[derive(Debug, PartialEq)]
+struct ParseError(String);
+
+impl From<std::num::ParseIntError> for ParseError {
+    fn from(e: std::num::ParseIntError) -> Self {
+        ParseError(e.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, TryIntoItems, TryIntoRows)]
+#[items_from(parse(&'a str), tuples(3), collections(vec), error_type(ParseError))]
+struct Int(i64);
+
+impl std::str::FromStr for Int {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Int)
+    }
+}
+
+#[accepts]
+fn ids(#[items] ids: Vec<Id>) -> Vec<Id> {
+    ids
+}
+
+#[accepts]
+fn queue(#[items] ids: VecDeque<Id>) -> VecDeque<Id> {
+    ids
+}
+
+#[accepts]
+fn sorted(#[items] ids: BTreeSet<Id>) -> Vec<Id> {
+    ids.into_iter().collect()
+}
+
+#[accepts]
+fn grid(#[rows] grid: Vec<Vec<Id>>) -> Vec<Vec<Id>> {
+    grid
+}
+
+#[accepts]
+fn row_sets(#[rows] grid: Vec<BTreeSet<Id>>) -> Vec<usize> {
+    grid.iter().map(BTreeSet::len).collect()
+}
+
+#[accepts]
+fn ints(#[try_items(ParseError)] ints: Vec<Int>) -> Result<Vec<Int>, ParseError> {
+    Ok(ints)
+}
+
+#[accepts]
+fn int_rows(#[try_rows(ParseError)] grid: Vec<Vec<Int>>) -> Result<Vec<Vec<Int>>, ParseError> {
+    Ok(grid)
+}
+
+#[accepts]
+fn pushed(#[items] mut ids: Vec<Id>, extra: u32) -> Vec<Id> {
+    ids.push(Id(extra));
+    ids
+}
+
+#[accepts(crate = ::itemize)]
+fn both(
+    #[items] left: Vec<Id>,
+    #[try_items(ParseError)] right: Vec<Int>,
+) -> Result<usize, ParseError> {
+    Ok(left.len() + right.len())
+}
+
+struct Registry(Vec<Id>);
+
+impl Registry {
+    #[accepts]
+    pub(crate) fn extend(&mut self, #[items] ids: Vec<Id>) -> usize {
+        self.0.extend(ids);
+        self.0.len()
+    }
+}
+
+#[accepts]
+unsafe fn tagged<T>(tag: T, #[items] ids: Vec<Id>) -> Vec<(T, Id)>
+where
+    T: Copy,
+{
+    #![allow(unused_unsafe)]
+    ids.into_iter().map(|id| (tag, id)).collect()
+}
+
+#[test]
+fn items() {
+    assert_eq!(ids((1, 2, 3)), vec![Id(1), Id(2), Id(3)]);
+    assert_eq!(ids(vec![4]), vec![Id(4)]);
+    assert_eq!(ids(5), vec![Id(5)]);
+    assert_eq!(queue([6, 7]), VecDeque::from([Id(6), Id(7)]));
+    assert_eq!(sorted((9, 8, 9)), vec![Id(8), Id(9)]);
+}
+
+#[test]
+fn rows() {
+    assert_eq!(
+        grid(((1, 2), [3, 4, 5])),
+        vec![vec![Id(1), Id(2)], vec![Id(3), Id(4), Id(5)]]
+    );
+    assert_eq!(row_sets((vec![1, 1], 2)), vec![1, 1]);
+}
+
+#[test]
+fn try_items() {
+    assert_eq!(ints(("1", "2")), Ok(vec![Int(1), Int(2)]));
+    assert_eq!(ints(vec!["3"]), Ok(vec![Int(3)]));
+    assert!(ints(vec!["x"]).is_err());
+}
+
+#[test]
+fn try_rows() {
+    assert_eq!(
+        int_rows((("1", "2"), vec!["3"])),
+        Ok(vec![vec![Int(1), Int(2)], vec![Int(3)]])
+    );
+    assert!(int_rows((vec!["4"], ("y", "5"))).is_err());
+}
+
+#[test]
+fn mutable_and_mixed() {
+    assert_eq!(pushed((1, 2), 3), vec![Id(1), Id(2), Id(3)]);
+    assert_eq!(both((1, 2), "3"), Ok(3));
+    assert!(both(1, "z").is_err());
+}
+
+#[test]
+fn signatures() {
+    let mut registry = Registry(vec![Id(1)]);
+    assert_eq!(registry.extend((2, 3)), 3);
+    assert_eq!(
+        unsafe { tagged('a', (4, 5)) },
+        vec![('a', Id(4)), ('a', Id(5))]
+    );
+}
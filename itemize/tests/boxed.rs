@@ -0,0 +1,110 @@
+//! Inputs of different types boxed behind the object-safe `Dyn*` traits, converted
+//! directly or passed on as inputs themselves.
+
+use std::num::ParseIntError;
+
+use itemize::{
+    BoxedItems, DynIntoItems, DynIntoRows, DynTryIntoItems, IntoBoxedItems, IntoItems, IntoRows,
+    TryIntoBoxedItems, TryIntoItems,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, IntoItems, IntoRows)]
+#[items_from(types(u32), tuples(3), collections(vec, array), wrap)]
+struct Id(u32);
+
+#[derive(Debug, PartialEq, TryIntoItems)]
+#[items_from(types(&'a str), tuples(2), collections(vec))]
+struct Port(u16);
+
+impl TryFrom<&str> for Port {
+    type Error = ParseIntError;
+    fn try_from(port: &str) -> Result<Self, Self::Error> {
+        port.parse().map(Port)
+    }
+}
+
+fn ids(input: impl IntoItems<Id>) -> Vec<Id> {
+    input.into_items().collect()
+}
+
+fn id_rows(input: impl IntoRows<Id>) -> Vec<Vec<Id>> {
+    input.into_rows().map(Iterator::collect).collect()
+}
+
+fn ports(input: impl TryIntoItems<Port, ParseIntError>) -> Result<Vec<Port>, ParseIntError> {
+    input.try_into_items().collect()
+}
+
+#[test]
+fn items() {
+    let queued: Vec<Box<dyn DynIntoItems<Id>>> = vec![
+        Box::new(1u32),
+        Box::new(vec![Id(2)]),
+        Box::new((3u32, 4u32)),
+        Box::new(vec![5u32]),
+        Box::new([Id(6), Id(7)]),
+    ];
+    let collected: Vec<Id> = queued
+        .into_iter()
+        .flat_map(|input| input.into_items_boxed())
+        .collect();
+    assert_eq!(collected, (1..=7).map(Id).collect::<Vec<_>>());
+
+    let boxed: Box<dyn DynIntoItems<Id>> = Box::new((8u32, Id(9)));
+    assert_eq!(ids(boxed), vec![Id(8), Id(9)]);
+
+    let erased: Vec<BoxedItems<'_, Id>> =
+        vec![10u32.into_boxed_items(), vec![11u32, 12].into_boxed_items()];
+    assert_eq!(erased.into_iter().flatten().count(), 3);
+}
+
+#[test]
+fn try_items() {
+    let queued: Vec<Box<dyn DynTryIntoItems<Port, ParseIntError>>> = vec![
+        Box::new("80"),
+        Box::new(("443", "8080")),
+        Box::new(vec!["22"]),
+    ];
+    let collected: Result<Vec<Port>, _> = queued
+        .into_iter()
+        .flat_map(|input| input.try_into_items_boxed())
+        .collect();
+    assert_eq!(
+        collected,
+        Ok(vec![Port(80), Port(443), Port(8080), Port(22)])
+    );
+
+    let boxed: Box<dyn DynTryIntoItems<Port, _>> = Box::new(("21", "23"));
+    assert_eq!(ports(boxed), Ok(vec![Port(21), Port(23)]));
+
+    let invalid: Box<dyn DynTryIntoItems<Port, _>> = Box::new(vec!["53", "x"]);
+    assert!(ports(invalid).is_err());
+    let mut checked = TryIntoBoxedItems::<Port, ParseIntError>::try_into_boxed_items(("y", "25"));
+    assert!(checked.next().is_some_and(|port| port.is_err()));
+}
+
+#[test]
+fn rows() {
+    let queued: Vec<Box<dyn DynIntoRows<Id>>> = vec![
+        Box::new(((1u32, 2u32), vec![3u32])),
+        Box::new(vec![vec![4u32]]),
+        Box::new([[Id(5), Id(6)]]),
+    ];
+    let collected: Vec<Vec<Id>> = queued
+        .into_iter()
+        .flat_map(|input| input.into_rows_boxed())
+        .map(Iterator::collect)
+        .collect();
+    assert_eq!(
+        collected,
+        vec![
+            vec![Id(1), Id(2)],
+            vec![Id(3)],
+            vec![Id(4)],
+            vec![Id(5), Id(6)],
+        ]
+    );
+
+    let boxed: Box<dyn DynIntoRows<Id>> = Box::new((7u32, [8u32, 9]));
+    assert_eq!(id_rows(boxed), vec![vec![Id(7)], vec![Id(8), Id(9)]]);
+}
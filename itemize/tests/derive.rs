@@ -0,0 +1,487 @@
+//! Values produced by the derive options.
+
+use itemize::{IntoItems, IntoRows, TryIntoItems, TryIntoRows};
+
+// Targets whose own parameters share names with the ones the derive injects
+#[derive(Debug, PartialEq, IntoItems, IntoRows)]
+#[items_from(types(&'a str), tuples(2), collections(vec, slice, array), copied, wrap)]
+struct Cell<'a>(&'a str);
+
+#[derive(Debug, PartialEq, TryIntoItems, TryIntoRows)]
+#[items_from(types(u8), tuples(2), collections(vec, slice), wrap)]
+struct Wrapper<E>(E);
+
+#[test]
+fn generic_names() {
+    fn into_items<'a>(x: impl IntoItems<Cell<'a>>) -> Vec<Cell<'a>> {
+        x.into_items().collect()
+    }
+    let cells = ["a", "b"];
+    assert_eq!(into_items(&cells[..]), vec![Cell("a"), Cell("b")]);
+    assert_eq!(into_items(("c", "d")), vec![Cell("c"), Cell("d")]);
+
+    fn try_into_items(
+        x: impl TryIntoItems<Wrapper<u16>, std::convert::Infallible>,
+    ) -> Vec<Wrapper<u16>> {
+        x.try_into_items().map(Result::unwrap).collect()
+    }
+    assert_eq!(try_into_items((1u8, 2u8)), vec![Wrapper(1), Wrapper(2)]);
+}
+
+// One instantiation per `target`, each with its own sources
+#[derive(Debug, PartialEq, IntoItems)]
+#[items_from(target = Setting<u64>, types(u64, u32), collections(vec), wrap)]
+#[items_from(target = Setting<std::path::PathBuf>, types(std::path::PathBuf, &'a str), wrap)]
+struct Setting<T>(T)
+where
+    T: Clone;
+
+#[test]
+fn targets() {
+    fn numbers(x: impl IntoItems<Setting<u64>>) -> Vec<u64> {
+        x.into_items().map(|item| item.0).collect()
+    }
+    fn paths(x: impl IntoItems<Setting<std::path::PathBuf>>) -> Vec<std::path::PathBuf> {
+        x.into_items().map(|item| item.0).collect()
+    }
+    assert_eq!(numbers(vec![1u32, 2]), vec![1, 2]);
+    assert_eq!(numbers(3u64), vec![3]);
+    assert_eq!(paths("a/b"), vec![std::path::PathBuf::from("a/b")]);
+}
+
+// `items(...)` and `rows(...)` override the shared options for each trait
+#[derive(IntoItems, IntoRows)]
+#[items_from(
+    types(u16),
+    collections(array),
+    wrap,
+    items(tuples(12)),
+    rows(tuples(3), collections(vec))
+)]
+struct Cell16(u16);
+
+#[test]
+fn sections() {
+    fn items(x: impl IntoItems<Cell16>) -> Vec<u16> {
+        x.into_items().map(|cell| cell.0).collect()
+    }
+    fn rows(x: impl IntoRows<Cell16>) -> usize {
+        x.into_rows().count()
+    }
+    assert_eq!(
+        items((
+            1u16, 2u16, 3u16, 4u16, 5u16, 6u16, 7u16, 8u16, 9u16, 10u16, 11u16, 12u16
+        )),
+        (1..=12).collect::<Vec<_>>()
+    );
+    assert_eq!(items([1u16, 2]), vec![1, 2]);
+    assert_eq!(rows(vec![[1u16], [2u16]]), 2);
+    assert_eq!(rows(((1u16, 2u16), [3u16], 4u16)), 3);
+}
+
+// `for<...>` declares parameters of a generic source type
+#[derive(IntoItems)]
+#[items_from(
+    types(for<T: std::fmt::Display> std::rc::Rc<T>, for<T: std::fmt::Display> Option<T>),
+    collections(vec)
+)]
+struct Message(String);
+
+impl<T: std::fmt::Display> From<std::rc::Rc<T>> for Message {
+    fn from(value: std::rc::Rc<T>) -> Self {
+        Message(value.to_string())
+    }
+}
+
+impl<T: std::fmt::Display> From<Option<T>> for Message {
+    fn from(value: Option<T>) -> Self {
+        Message(value.map(|value| value.to_string()).unwrap_or_default())
+    }
+}
+
+#[test]
+fn generic_sources() {
+    fn messages(x: impl IntoItems<Message>) -> Vec<String> {
+        x.into_items().map(|message| message.0).collect()
+    }
+    assert_eq!(messages(std::rc::Rc::new(1)), vec!["1"]);
+    assert_eq!(messages(Some('a')), vec!["a"]);
+    assert_eq!(messages(vec![None::<u8>, Some(2)]), vec!["", "2"]);
+}
+
+// Tuples larger than the conversions shared by `itemize` get their bodies inline
+#[derive(IntoItems, TryIntoItems, IntoRows, TryIntoRows)]
+#[items_from(types(u8), tuples(exact(17)))]
+struct Byte(u8);
+
+impl From<u8> for Byte {
+    fn from(byte: u8) -> Self {
+        Byte(byte)
+    }
+}
+
+#[test]
+fn large_tuples() {
+    fn items(x: impl IntoItems<Byte>) -> Vec<u8> {
+        x.into_items().map(|byte| byte.0).collect()
+    }
+    fn try_items(x: impl TryIntoItems<Byte, std::convert::Infallible>) -> usize {
+        x.try_into_items().count()
+    }
+    fn rows(x: impl IntoRows<Byte>) -> usize {
+        x.into_rows().count()
+    }
+    fn try_rows(x: impl TryIntoRows<Byte, std::convert::Infallible>) -> usize {
+        x.try_into_rows().count()
+    }
+    let bytes = (
+        0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8,
+    );
+    assert_eq!(items(bytes), (0..17).collect::<Vec<_>>());
+    assert_eq!(try_items(bytes), 17);
+    assert_eq!(rows(bytes), 17);
+    assert_eq!(try_rows(bytes), 17);
+}
+
+// cloned - borrowed sources clone into owned ones, next to owned elements and the
+// target itself
+#[derive(Debug, Clone, PartialEq, IntoItems, TryIntoItems)]
+#[items_from(types(String), tuples(2), collections(vec, slice), cloned, wrap)]
+struct Name(String);
+
+#[test]
+fn cloned() {
+    fn names(x: impl IntoItems<Name>) -> Vec<Name> {
+        x.into_items().collect()
+    }
+    fn checked_names(
+        x: impl TryIntoItems<Name, std::convert::Infallible>,
+    ) -> Result<Vec<Name>, std::convert::Infallible> {
+        x.try_into_items().collect()
+    }
+    let s = "b".to_string();
+    let expected = vec![Name("a".into()), Name("b".into())];
+    assert_eq!(names((Name("a".into()), s.clone())), expected);
+    assert_eq!(names((Name("a".into()), &s)), expected);
+    let strings = vec!["a".to_string(), s.clone()];
+    assert_eq!(names(&strings), expected);
+    assert_eq!(names(strings), expected);
+    assert_eq!(
+        checked_names((Name("a".into()), s.clone())),
+        Ok(expected.clone())
+    );
+    assert_eq!(
+        checked_names(vec![Name("a".into())]),
+        Ok(vec![Name("a".into())])
+    );
+}
+
+// wrap - `IntoItems` generates `From<Source>` wrapping into the field, while
+// `TryIntoItems` converts through the field's own `TryFrom<Source>` in its impls
+#[derive(Debug, PartialEq, TryIntoItems)]
+#[items_from(
+    types(u16, u32, i64),
+    tuples(2),
+    collections(vec),
+    error_type(std::num::TryFromIntError),
+    wrap
+)]
+struct Port {
+    port: u16,
+}
+
+#[derive(Debug, PartialEq, IntoItems, TryIntoItems)]
+#[items_from(types(u16, u32), tuples(2), collections(vec, array), wrap)]
+struct Count(u32);
+
+#[test]
+fn wrap() {
+    fn ports(
+        x: impl TryIntoItems<Port, std::num::TryFromIntError>,
+    ) -> Result<Vec<Port>, std::num::TryFromIntError> {
+        x.try_into_items().collect()
+    }
+    assert_eq!(
+        ports((80u16, 443u32)),
+        Ok(vec![Port { port: 80 }, Port { port: 443 }])
+    );
+    assert!(ports(vec![-1i64]).is_err());
+
+    fn counts(x: impl IntoItems<Count>) -> Vec<Count> {
+        x.into_items().collect()
+    }
+    fn checked_counts(
+        x: impl TryIntoItems<Count, std::convert::Infallible>,
+    ) -> Result<Vec<Count>, std::convert::Infallible> {
+        x.try_into_items().collect()
+    }
+    assert_eq!(counts((1u16, 2u32)), vec![Count(1), Count(2)]);
+    assert_eq!(Count::from(3u16), Count(3));
+    assert_eq!(checked_counts((1u16, 2u32)), Ok(vec![Count(1), Count(2)]));
+    assert_eq!(checked_counts(vec![3u16]), Ok(vec![Count(3)]));
+    assert_eq!(checked_counts([4u32, 5]), Ok(vec![Count(4), Count(5)]));
+    // Both traits accept the target itself as an element
+    assert_eq!(counts(vec![Count(6)]), vec![Count(6)]);
+    assert_eq!(checked_counts(vec![Count(6)]), Ok(vec![Count(6)]));
+    assert_eq!(
+        checked_counts((Count(7), 8u16)),
+        Ok(vec![Count(7), Count(8)])
+    );
+    assert_eq!(
+        ports((Port { port: 22 }, 23u16)),
+        Ok(vec![Port { port: 22 }, Port { port: 23 }])
+    );
+}
+
+// Per-variant sources - `IntoItems` generates `From` into the matching variant and
+// `TryIntoItems` converts into its field
+#[derive(Debug, PartialEq, IntoItems, IntoRows, TryIntoItems)]
+#[items_from(tuples(3), collections(vec))]
+enum Value {
+    #[items_from(types(i64, i32))]
+    Int(i64),
+    #[items_from(types(f64))]
+    Float(f64),
+    #[items_from(types(String, &'a str))]
+    Text { text: String },
+}
+
+#[derive(Debug, PartialEq, TryIntoItems)]
+#[items_from(tuples(2), error_type(std::num::TryFromIntError))]
+enum Small {
+    #[items_from(types(u8, u16, u32))]
+    Byte(u8),
+    #[items_from(types(char))]
+    Char(char),
+}
+
+#[test]
+fn variants() {
+    fn values(x: impl IntoItems<Value>) -> Vec<Value> {
+        x.into_items().collect()
+    }
+    assert_eq!(
+        values((1, "a", 2.5)),
+        vec![
+            Value::Int(1),
+            Value::Text { text: "a".into() },
+            Value::Float(2.5)
+        ]
+    );
+    assert_eq!(
+        values(vec!["b", "c"]),
+        vec![
+            Value::Text { text: "b".into() },
+            Value::Text { text: "c".into() }
+        ]
+    );
+
+    fn checked_values(
+        x: impl TryIntoItems<Value, std::convert::Infallible>,
+    ) -> Result<Vec<Value>, std::convert::Infallible> {
+        x.try_into_items().collect()
+    }
+    assert_eq!(
+        checked_values((2i32, 3.5, "d")),
+        Ok(vec![
+            Value::Int(2),
+            Value::Float(3.5),
+            Value::Text { text: "d".into() }
+        ])
+    );
+    assert_eq!(checked_values(vec![4i64]), Ok(vec![Value::Int(4)]));
+
+    fn small(
+        x: impl TryIntoItems<Small, std::num::TryFromIntError>,
+    ) -> Result<Vec<Small>, std::num::TryFromIntError> {
+        x.try_into_items().collect()
+    }
+    assert_eq!(
+        small((7u32, 'x')),
+        Ok(vec![Small::Byte(7), Small::Char('x')])
+    );
+    assert!(small(300u16).is_err());
+}
+
+// via - sources convert through an intermediate type (`&str -> String -> Label`,
+// `u8 -> u32 -> Label`), and tuple or collection elements through their own impls
+#[derive(Debug, PartialEq, IntoItems)]
+#[items_from(types(String, &'a str via String, u8 via u32), tuples(2), collections(vec))]
+struct Label(String);
+
+impl From<String> for Label {
+    fn from(value: String) -> Self {
+        Label(value)
+    }
+}
+
+impl From<u32> for Label {
+    fn from(value: u32) -> Self {
+        Label(value.to_string())
+    }
+}
+
+// For fallible traits both `TryFrom` steps run and their errors merge into `error_type`
+#[derive(Debug, PartialEq, TryIntoItems)]
+#[items_from(
+    types(u8, i64 via u32),
+    tuples(2),
+    collections(vec),
+    error_type(std::num::TryFromIntError),
+    wrap
+)]
+struct Octet(u8);
+
+#[test]
+fn via() {
+    fn labels(x: impl IntoItems<Label>) -> Vec<Label> {
+        x.into_items().collect()
+    }
+    assert_eq!(labels("a"), vec![Label("a".into())]);
+    assert_eq!(labels(7u8), vec![Label("7".into())]);
+    assert_eq!(
+        labels(("b", 8u8)),
+        vec![Label("b".into()), Label("8".into())]
+    );
+    assert_eq!(
+        labels(vec!["c", "d"]),
+        vec![Label("c".into()), Label("d".into())]
+    );
+    assert_eq!(labels(vec![9u8]), vec![Label("9".into())]);
+    // The target itself is still accepted as an element
+    assert_eq!(labels(vec![Label("e".into())]), vec![Label("e".into())]);
+    assert_eq!(
+        labels((Label("f".into()), 10u8)),
+        vec![Label("f".into()), Label("10".into())]
+    );
+
+    fn octets(
+        x: impl TryIntoItems<Octet, std::num::TryFromIntError>,
+    ) -> Result<Vec<Octet>, std::num::TryFromIntError> {
+        x.try_into_items().collect()
+    }
+    assert_eq!(octets(2i64), Ok(vec![Octet(2)]));
+    assert!(octets(-1i64).is_err());
+    assert!(octets(256i64).is_err());
+    assert_eq!(octets((1u8, 2i64)), Ok(vec![Octet(1), Octet(2)]));
+    assert_eq!(octets(vec![3i64, 4]), Ok(vec![Octet(3), Octet(4)]));
+    assert!(octets(vec![5i64, 300]).is_err());
+    assert_eq!(octets(vec![Octet(6)]), Ok(vec![Octet(6)]));
+    assert_eq!(octets((Octet(7), 8i64)), Ok(vec![Octet(7), Octet(8)]));
+}
+
+// parse - string sources go through `FromStr` inside the impls, leaving `TryFrom` to
+// the target
+#[derive(Debug, PartialEq, TryIntoItems)]
+#[items_from(
+    parse(&'a str, String),
+    tuples(2),
+    collections(vec),
+    error_type(std::num::ParseIntError)
+)]
+struct Num(i64);
+
+impl std::str::FromStr for Num {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Num)
+    }
+}
+
+impl TryFrom<&str> for Num {
+    type Error = String;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.strip_prefix('#')
+            .and_then(|s| s.parse().ok())
+            .map(Num)
+            .ok_or_else(|| format!("expected `#<number>`, found `{s}`"))
+    }
+}
+
+#[test]
+fn parse() {
+    fn nums(
+        x: impl TryIntoItems<Num, std::num::ParseIntError>,
+    ) -> Result<Vec<Num>, std::num::ParseIntError> {
+        x.try_into_items().collect()
+    }
+    assert_eq!(nums("1"), Ok(vec![Num(1)]));
+    assert_eq!(nums(("2", "3".to_string())), Ok(vec![Num(2), Num(3)]));
+    assert_eq!(nums(vec!["4", "5"]), Ok(vec![Num(4), Num(5)]));
+    assert_eq!(nums(vec!["6".to_string()]), Ok(vec![Num(6)]));
+    assert!(nums(("7", "x")).is_err());
+    assert_eq!(nums(vec![Num(9)]), Ok(vec![Num(9)]));
+    assert_eq!(nums((Num(10), "11")), Ok(vec![Num(10), Num(11)]));
+    assert_eq!(Num::try_from("#8"), Ok(Num(8)));
+}
+
+// tuples(lean) - `LeanTuple` inputs go through the impls compiled into `itemize`, with
+// each element converted through its own single-value impl
+#[cfg(feature = "tuples-8")]
+mod lean {
+    use itemize::{IntoItems, IntoRows, LeanTuple, TryIntoItems, TryIntoRows};
+
+    #[derive(Debug, PartialEq, IntoItems, IntoRows, TryIntoItems, TryIntoRows)]
+    #[items_from(types(u8, u16), tuples(lean), collections(vec), wrap)]
+    struct Id(u32);
+
+    #[derive(Debug, PartialEq, TryIntoItems)]
+    #[items_from(parse(&'a str), tuples(lean), error_type(std::num::ParseIntError))]
+    #[items_from(parse(String), error_type(Box<dyn std::error::Error>))]
+    struct Int(i64);
+
+    impl std::str::FromStr for Int {
+        type Err = std::num::ParseIntError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse().map(Int)
+        }
+    }
+
+    #[test]
+    fn items() {
+        let ids: Vec<Id> = LeanTuple((1u8, 2u16, 3u8)).into_items().collect();
+        assert_eq!(ids, vec![Id(1), Id(2), Id(3)]);
+
+        let checked: Result<Vec<Id>, std::convert::Infallible> =
+            LeanTuple((4u16, 5u8)).try_into_items().collect();
+        assert_eq!(checked, Ok(vec![Id(4), Id(5)]));
+
+        let ints: Result<Vec<Int>, std::num::ParseIntError> =
+            LeanTuple(("6", "7")).try_into_items().collect();
+        assert_eq!(ints, Ok(vec![Int(6), Int(7)]));
+        let ints: Result<Vec<Int>, std::num::ParseIntError> =
+            LeanTuple(("8", "x")).try_into_items().collect();
+        assert!(ints.is_err());
+        let boxed: Result<Vec<Int>, Box<dyn std::error::Error>> =
+            LeanTuple(("9".to_string(), "10".to_string()))
+                .try_into_items()
+                .collect();
+        assert_eq!(boxed.unwrap(), vec![Int(9), Int(10)]);
+    }
+
+    #[test]
+    fn rows() {
+        fn rows(x: impl IntoRows<Id>) -> Vec<Vec<Id>> {
+            x.into_rows().map(Iterator::collect).collect()
+        }
+        fn try_rows(x: impl TryIntoRows<Id, std::convert::Infallible>) -> usize {
+            x.try_into_rows().map(Iterator::count).sum()
+        }
+        assert_eq!(
+            rows(LeanTuple((vec![1u8, 2], 3u16))),
+            vec![vec![Id(1), Id(2)], vec![Id(3)]]
+        );
+        assert_eq!(try_rows(LeanTuple((vec![4u16], vec![5u8, 6]))), 3);
+    }
+
+    #[cfg(feature = "tuples-16")]
+    #[test]
+    fn wide() {
+        let ids: Vec<Id> = LeanTuple((
+            1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8,
+        ))
+        .into_items()
+        .collect();
+        assert_eq!(ids, (1..=12).map(Id).collect::<Vec<_>>());
+    }
+}
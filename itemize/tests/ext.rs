@@ -0,0 +1,78 @@
+//! The extension traits must coexist with iterator extensions using the same method
+//! names, as `itertools::Itertools` does.
+
+use itemize::{
+    IntoItems, IntoRows, ItemsExt, RowsExt, ShapeError, TryIntoItems, TryIntoRows, TryItemsExt,
+    TryRowsExt,
+};
+
+/// Mirrors the `Itertools` methods sharing a name with the extension traits.
+trait Itertools: Iterator + Sized {
+    fn collect_vec(self) -> Vec<Self::Item> {
+        self.collect()
+    }
+
+    fn exactly_one(mut self) -> Option<Self::Item> {
+        let first = self.next()?;
+        self.next().is_none().then_some(first)
+    }
+
+    fn at_most_one(mut self) -> Option<Option<Self::Item>> {
+        let first = self.next();
+        self.next().is_none().then_some(first)
+    }
+}
+
+impl<I: Iterator> Itertools for I {}
+
+#[derive(Debug, PartialEq, IntoItems, TryIntoItems, IntoRows, TryIntoRows)]
+#[items_from(types(u32), tuples(3), collections(vec))]
+struct Id(u32);
+
+impl From<u32> for Id {
+    fn from(id: u32) -> Self {
+        Id(id)
+    }
+}
+
+#[test]
+fn iterators_use_itertools() {
+    assert_eq!((0..3).collect_vec(), vec![0, 1, 2]);
+    assert_eq!([7].into_iter().exactly_one(), Some(7));
+    assert_eq!((0..2).at_most_one(), None);
+}
+
+#[test]
+fn inputs_use_extensions() {
+    fn ids(input: impl IntoItems<Id>) -> Vec<Id> {
+        input.collect_vec()
+    }
+    fn primary(input: impl IntoItems<Id>) -> Result<Id, ShapeError> {
+        input.exactly_one()
+    }
+    fn optional(input: impl IntoItems<Id>) -> Result<Option<Id>, ShapeError> {
+        input.at_most_one()
+    }
+    assert_eq!(ids((1u32, 2u32)), vec![Id(1), Id(2)]);
+    assert_eq!(primary(vec![3u32]), Ok(Id(3)));
+    assert_eq!(
+        optional((1u32, 2u32)),
+        Err(ShapeError::TooMany { max: 1, found: 2 })
+    );
+    assert_eq!(
+        TryItemsExt::<Id, std::convert::Infallible>::try_collect_vec(vec![4u32]),
+        Ok(vec![Id(4)])
+    );
+    assert_eq!(
+        RowsExt::<Id>::collect_matrix((vec![1u32], vec![2u32, 3])),
+        Err(ShapeError::Ragged {
+            row: 1,
+            expected: 1,
+            found: 2
+        })
+    );
+    assert_eq!(
+        TryRowsExt::<Id, std::convert::Infallible>::try_collect_rows(((1u32,), vec![2u32])),
+        Ok(vec![vec![Id(1)], vec![Id(2)]])
+    );
+}
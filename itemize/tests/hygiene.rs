@@ -0,0 +1,244 @@
+//! Derive output must not depend on the prelude or on names in scope at the
+//! derive site.
+
+#[no_implicit_prelude]
+mod no_prelude {
+    use ::core::prelude::v1::derive;
+    use ::core::result::Result;
+    use ::itemize::{IntoItems, IntoRows, TryIntoItems, TryIntoRows};
+    use ::std::string::String;
+
+    #[derive(IntoItems, IntoRows)]
+    #[items_from(
+        types(u32, u8 via u32),
+        tuples(3),
+        collections(vec, slice, array),
+        cloned,
+        wrap
+    )]
+    pub struct Count(pub u32);
+
+    #[derive(TryIntoItems, TryIntoRows)]
+    #[items_from(
+        parse(String, &'a str),
+        tuples(3),
+        collections(vec, slice, array),
+        error_type(::core::num::ParseIntError)
+    )]
+    pub struct Int(pub i64);
+
+    impl ::core::str::FromStr for Int {
+        type Err = ::core::num::ParseIntError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            <i64 as ::core::str::FromStr>::from_str(s).map(Int)
+        }
+    }
+
+    #[derive(IntoItems)]
+    #[items_from(tuples(2), collections(vec))]
+    pub enum Value {
+        #[items_from(types(i64))]
+        Int(i64),
+        #[items_from(types(String))]
+        Text(String),
+    }
+
+    #[derive(TryIntoItems)]
+    #[items_from(tuples(2), error_type(::core::num::TryFromIntError))]
+    pub enum Small {
+        #[items_from(types(u16))]
+        Byte(u8),
+    }
+
+    pub fn check_try_enum() -> bool {
+        let mut items =
+            TryIntoItems::<Small, ::core::num::TryFromIntError>::try_into_items((7u16, 300u16));
+        ::core::matches!(
+            ::core::iter::Iterator::next(&mut items),
+            ::core::option::Option::Some(Result::Ok(Small::Byte(7)))
+        ) && ::core::matches!(
+            ::core::iter::Iterator::next(&mut items),
+            ::core::option::Option::Some(Result::Err(_))
+        )
+    }
+}
+
+mod shadowed {
+    #![allow(dead_code, non_camel_case_types, clippy::upper_case_acronyms)]
+
+    use ::itemize::{IntoItems, IntoRows, TryIntoItems, TryIntoRows};
+
+    // Names the generated code must not pick up from the derive site. Types written
+    // in the attribute itself still resolve here, so `str` is left alone.
+    mod itemize {}
+    type Result<T> = core::result::Result<T, ()>;
+    struct Vec;
+    struct Option;
+    struct Iterator;
+    struct usize;
+    trait Into {}
+    trait From {}
+    enum Either {
+        Left,
+        Right,
+    }
+    #[allow(unused_imports)]
+    use Either::*;
+
+    #[derive(IntoItems, IntoRows)]
+    #[items_from(
+        types(u32, &'a str via String),
+        tuples(3),
+        collections(vec, slice, array),
+        crate = ::itemize
+    )]
+    pub struct Count(pub u32);
+
+    impl core::convert::From<u32> for Count {
+        fn from(value: u32) -> Self {
+            Count(value)
+        }
+    }
+
+    impl core::convert::From<String> for Count {
+        fn from(value: String) -> Self {
+            Count(value.len() as u32)
+        }
+    }
+
+    #[derive(TryIntoItems, TryIntoRows)]
+    #[items_from(
+        parse(String, &'a str),
+        tuples(3),
+        collections(vec, slice, array),
+        crate = ::itemize
+    )]
+    pub struct Int(pub i64);
+
+    impl core::str::FromStr for Int {
+        type Err = core::num::ParseIntError;
+        fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+            s.parse().map(Int)
+        }
+    }
+}
+
+mod where_clause {
+    #![allow(non_camel_case_types)]
+
+    use ::itemize::IntoItems;
+
+    // Named only in the where clause, so the injected element parameter must avoid it.
+    pub struct __T;
+
+    pub trait Tagged<M> {}
+
+    impl Tagged<__T> for u32 {}
+
+    #[derive(IntoItems)]
+    #[items_from(types(u32), tuples(2), collections(vec))]
+    pub struct Num<T>(pub T)
+    where
+        T: Tagged<__T>;
+
+    impl From<u32> for Num<u32> {
+        fn from(value: u32) -> Self {
+            Num(value)
+        }
+    }
+}
+
+use itemize::{IntoItems, IntoRows, TryIntoItems, TryIntoRows};
+
+fn counts(input: impl IntoItems<no_prelude::Count>) -> Vec<u32> {
+    input.into_items().map(|count| count.0).collect()
+}
+
+fn count_rows(input: impl IntoRows<no_prelude::Count>) -> Vec<Vec<u32>> {
+    input
+        .into_rows()
+        .map(|row| row.map(|count| count.0).collect())
+        .collect()
+}
+
+fn ints(
+    input: impl TryIntoItems<no_prelude::Int, std::num::ParseIntError>,
+) -> Result<Vec<i64>, std::num::ParseIntError> {
+    input
+        .try_into_items()
+        .map(|int| int.map(|int| int.0))
+        .collect()
+}
+
+fn int_rows(
+    input: impl TryIntoRows<no_prelude::Int, std::num::ParseIntError>,
+) -> Result<Vec<Vec<i64>>, std::num::ParseIntError> {
+    input
+        .try_into_rows()
+        .map(|row| row.map(|int| int.map(|int| int.0)).collect())
+        .collect()
+}
+
+#[test]
+fn no_prelude_into_items() {
+    let borrowed = vec![4u32, 5];
+    assert_eq!(counts((1u32, 2u8, &borrowed[0])), vec![1, 2, 4]);
+    assert_eq!(counts(&borrowed), vec![4, 5]);
+    assert_eq!(counts([6u32, 7]), vec![6, 7]);
+    assert_eq!(
+        count_rows((vec![1u32, 2], [3u32])),
+        vec![vec![1, 2], vec![3]]
+    );
+}
+
+#[test]
+fn no_prelude_try_into_items() {
+    assert_eq!(ints(("1", "2".to_string(), "3")), Ok(vec![1, 2, 3]));
+    assert!(ints(vec!["x"]).is_err());
+    assert_eq!(
+        int_rows((("1", "2".to_string()), ["3"])),
+        Ok(vec![vec![1, 2], vec![3]])
+    );
+}
+
+#[test]
+fn no_prelude_enum() {
+    let values: Vec<_> = (1i64, "a".to_string()).into_items().collect();
+    assert!(matches!(
+        values.as_slice(),
+        [no_prelude::Value::Int(1), no_prelude::Value::Text(_)]
+    ));
+    assert!(no_prelude::check_try_enum());
+}
+
+#[test]
+fn where_clause_names() {
+    fn nums(input: impl IntoItems<where_clause::Num<u32>>) -> Vec<u32> {
+        input.into_items().map(|num| num.0).collect()
+    }
+    assert_eq!(nums(vec![1u32, 2]), vec![1, 2]);
+    assert_eq!(nums((3u32, 4u32)), vec![3, 4]);
+}
+
+#[test]
+fn shadowed_names() {
+    fn counts(input: impl IntoItems<shadowed::Count>) -> Vec<u32> {
+        input.into_items().map(|count| count.0).collect()
+    }
+    fn ints(input: impl TryIntoItems<shadowed::Int, std::num::ParseIntError>) -> Vec<i64> {
+        input.try_into_items().map(|int| int.unwrap().0).collect()
+    }
+    fn count_rows(input: impl IntoRows<shadowed::Count>) -> Vec<Vec<u32>> {
+        input
+            .into_rows()
+            .map(|row| row.map(|count| count.0).collect())
+            .collect()
+    }
+    assert_eq!(counts((1u32, 2u32)), vec![1, 2]);
+    assert_eq!(counts("abc"), vec![3]);
+    assert_eq!(ints(("1", "2")), vec![1, 2]);
+    assert_eq!(
+        count_rows(((1u32, 2u32), vec![3u32])),
+        vec![vec![1, 2], vec![3]]
+    );
+}
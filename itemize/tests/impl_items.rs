@@ -0,0 +1,83 @@
+//! Impls generated by `impl_items!` for generic targets and for newtypes wrapping
+//! targets from other crates.
+
+use std::path::PathBuf;
+
+use itemize::{IntoItems, IntoRows, TryIntoItems, impl_items};
+
+#[derive(Debug, PartialEq)]
+struct Tagged<T> {
+    value: T,
+}
+
+impl<T: From<u8>> From<u8> for Tagged<T> {
+    fn from(value: u8) -> Self {
+        Tagged {
+            value: value.into(),
+        }
+    }
+}
+
+impl_items!(IntoItems, IntoRows for<T: From<u8>> Tagged<T>: types(u8), tuples(2), collections(vec));
+
+#[test]
+fn generic_target() {
+    fn tagged(input: impl IntoItems<Tagged<u64>>) -> Vec<u64> {
+        input.into_items().map(|tagged| tagged.value).collect()
+    }
+    fn rows(input: impl IntoRows<Tagged<u32>>) -> usize {
+        input.into_rows().count()
+    }
+    assert_eq!(tagged((1u8, 2u8)), vec![1, 2]);
+    assert_eq!(tagged(vec![3u8]), vec![3]);
+    assert_eq!(rows((vec![1u8], (2u8, 3u8))), 2);
+}
+
+impl_items!(
+    IntoItems, TryIntoItems for PathBuf as #[derive(Debug, PartialEq)] Paths:
+        types(PathBuf, &'a str, String),
+        tuples(2),
+        collections(vec),
+);
+
+impl_items!(
+    IntoItems, TryIntoItems for u64 as #[derive(Debug, PartialEq)] pub(crate) Id:
+        types(u64, u32, u16 via u32),
+        tuples(3),
+        collections(vec, array),
+        error_type(std::num::TryFromIntError),
+);
+
+impl_items!(IntoItems for<T: Clone> Vec<T> as Batch: types(&'a [T]), tuples(2));
+
+#[test]
+fn foreign_targets() {
+    fn paths(input: impl IntoItems<Paths>) -> Vec<PathBuf> {
+        input.into_items().map(PathBuf::from).collect()
+    }
+    assert_eq!(
+        paths(("a", String::from("b"))),
+        vec![PathBuf::from("a"), PathBuf::from("b")]
+    );
+    assert_eq!(paths(vec![PathBuf::from("c")]), vec![PathBuf::from("c")]);
+    assert_eq!(Paths::from("d").0, PathBuf::from("d"));
+
+    fn ids(input: impl IntoItems<Id>) -> Vec<u64> {
+        input.into_items().map(u64::from).collect()
+    }
+    fn checked_ids(
+        input: impl TryIntoItems<Id, std::num::TryFromIntError>,
+    ) -> Result<Vec<u64>, std::num::TryFromIntError> {
+        input
+            .try_into_items()
+            .map(|id| id.map(|Id(id)| id))
+            .collect()
+    }
+    assert_eq!(ids((1u64, 2u32, 3u16)), vec![1, 2, 3]);
+    assert_eq!(ids([4u32, 5]), vec![4, 5]);
+    assert_eq!(checked_ids(vec![6u16]), Ok(vec![6]));
+
+    let batches: Vec<Batch<u8>> = (&[1u8, 2][..], &[3u8][..]).into_items().collect();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(Vec::from(batches.into_iter().next().unwrap()), vec![1, 2]);
+}
@@ -0,0 +1,13 @@
+//! Diagnostics reported for unsupported inputs and invalid attributes.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    // The `LeanTuple` impls of the `tuples-*` features change the diagnostics of
+    // `tuples(lean)` and the candidates listed for unaccepted inputs.
+    #[cfg(not(feature = "tuples-8"))]
+    t.compile_fail("tests/ui/no_tuples_feature/*.rs");
+    #[cfg(feature = "tuples-8")]
+    t.compile_fail("tests/ui/tuples_feature/*.rs");
+}
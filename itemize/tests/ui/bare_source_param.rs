@@ -0,0 +1,11 @@
+use itemize::IntoItems;
+
+#[derive(IntoItems)]
+#[items_from(types(for<T: std::fmt::Display> T))]
+struct Message(String);
+
+#[derive(IntoItems)]
+#[items_from(types(for<T: std::fmt::Display> &'a Box<T>))]
+struct Label(String);
+
+fn main() {}
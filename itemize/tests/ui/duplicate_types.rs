@@ -0,0 +1,7 @@
+use itemize::IntoItems;
+
+#[derive(IntoItems)]
+#[items_from(types(u32, u8, u32), wrap)]
+struct Count(u32);
+
+fn main() {}
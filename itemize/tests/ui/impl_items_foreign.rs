@@ -0,0 +1,7 @@
+itemize::impl_items!(IntoItems for u64: types(u32));
+
+itemize::impl_items!(IntoItems for std::path::PathBuf: types(String));
+
+itemize::impl_items!(IntoItems for (String, u8): types(String));
+
+fn main() {}
@@ -0,0 +1,11 @@
+struct Count(u32);
+
+impl From<u32> for Count {
+    fn from(value: u32) -> Self {
+        Count(value)
+    }
+}
+
+itemize::impl_items!(IntoItems, TryIntoRows for Count: types(u32), parse(String), error_type(String));
+
+fn main() {}
@@ -0,0 +1,11 @@
+struct Tagged<T>(T);
+
+impl<T> From<Option<T>> for Tagged<Option<T>> {
+    fn from(value: Option<T>) -> Self {
+        Tagged(value)
+    }
+}
+
+itemize::impl_items!(IntoItems for<T> Tagged<T>: types(for<T> Option<T>));
+
+fn main() {}
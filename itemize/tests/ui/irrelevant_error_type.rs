@@ -0,0 +1,24 @@
+use itemize::{IntoItems, IntoRows, TryIntoItems};
+
+#[derive(IntoItems, IntoRows)]
+#[items_from(types(u32), error_type(String))]
+struct Count(u32);
+
+impl From<u32> for Count {
+    fn from(value: u32) -> Self {
+        Count(value)
+    }
+}
+
+// `TryIntoItems` reads the shared options, but not those of `rows(...)`
+#[derive(IntoRows, TryIntoItems)]
+#[items_from(types(u32), error_type(std::convert::Infallible), rows(error_type(u8)))]
+struct Total(u32);
+
+impl From<u32> for Total {
+    fn from(value: u32) -> Self {
+        Total(value)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use itemize::{IntoItems, TryIntoRows};
+
+#[derive(Debug, IntoItems, TryIntoRows)]
+#[items_from(types(u32), wrap, parse(String), error_type(std::num::ParseIntError))]
+struct Count(u32);
+
+impl std::str::FromStr for Count {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Count)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use itemize::IntoRows;
+
+#[derive(IntoRows)]
+#[items_from(types(u32), wrap)]
+struct Count(u32);
+
+fn main() {}
@@ -0,0 +1,21 @@
+use itemize::IntoItems;
+
+#[derive(IntoItems)]
+#[items_from(types(String, char), tuples(2), collections(vec))]
+struct Name(String);
+
+impl From<String> for Name {
+    fn from(value: String) -> Self {
+        Name(value)
+    }
+}
+
+fn names(input: impl IntoItems<Name>) -> usize {
+    input.into_items().count()
+}
+
+fn main() {
+    names('a');
+    names(vec![1u8]);
+    names((String::new(), 1u8));
+}
@@ -0,0 +1,14 @@
+use itemize::IntoItems;
+
+#[derive(IntoItems)]
+#[items_from(types(u32), tuples(2), wrap)]
+struct Count(u32);
+
+fn counts(input: impl IntoItems<Count>) -> usize {
+    input.into_items().count()
+}
+
+fn main() {
+    counts(vec![1u32]);
+    counts((vec![1u32], [2u32, 3]));
+}
@@ -0,0 +1,13 @@
+use itemize::{IntoItems, IntoRows};
+
+#[derive(IntoItems, IntoRows)]
+#[items_from(types(u32), tuples(2), wrap)]
+struct Count(u32);
+
+fn rows(input: impl IntoRows<Count>) -> usize {
+    input.into_rows().count()
+}
+
+fn main() {
+    rows(vec![vec![1u32]]);
+}
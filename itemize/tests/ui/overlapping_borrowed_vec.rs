@@ -0,0 +1,7 @@
+use itemize::IntoItems;
+
+#[derive(IntoItems)]
+#[items_from(types(u32, Vec<u32>), collections(slice), cloned)]
+struct Count(u32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use itemize::IntoItems;
+
+#[derive(IntoItems)]
+#[items_from(types(u32, (u32, u32)), tuples(2))]
+struct Count(u32);
+
+fn main() {}
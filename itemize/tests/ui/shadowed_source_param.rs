@@ -0,0 +1,7 @@
+use itemize::IntoItems;
+
+#[derive(IntoItems)]
+#[items_from(types(for<T: Clone> Option<T>))]
+struct Tagged<T>(T);
+
+fn main() {}
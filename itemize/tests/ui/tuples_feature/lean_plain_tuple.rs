@@ -0,0 +1,13 @@
+use itemize::IntoItems;
+
+#[derive(IntoItems)]
+#[items_from(types(u32), tuples(lean), wrap)]
+struct Count(u32);
+
+fn counts(input: impl IntoItems<Count>) -> usize {
+    input.into_items().count()
+}
+
+fn main() {
+    counts((1u32, 2u32));
+}
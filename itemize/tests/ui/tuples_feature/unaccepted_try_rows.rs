@@ -0,0 +1,20 @@
+use itemize::TryIntoRows;
+
+#[derive(TryIntoRows)]
+#[items_from(types(u32), tuples(2), error_type(std::num::TryFromIntError))]
+struct Small(u8);
+
+impl TryFrom<u32> for Small {
+    type Error = std::num::TryFromIntError;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        u8::try_from(value).map(Small)
+    }
+}
+
+fn rows(input: impl TryIntoRows<Small, std::num::TryFromIntError>) -> usize {
+    input.try_into_rows().count()
+}
+
+fn main() {
+    rows([[1u32]]);
+}
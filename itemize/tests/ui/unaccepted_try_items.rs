@@ -0,0 +1,20 @@
+use itemize::TryIntoItems;
+
+#[derive(TryIntoItems)]
+#[items_from(parse(&'a str), error_type(std::num::ParseIntError))]
+struct Int(i64);
+
+impl std::str::FromStr for Int {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Int)
+    }
+}
+
+fn ints(input: impl TryIntoItems<Int, String>) -> usize {
+    input.try_into_items().count()
+}
+
+fn main() {
+    ints("1");
+}
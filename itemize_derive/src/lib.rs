@@ -19,3 +19,13 @@ pub fn derive_try_into_items(input: TokenStream) -> TokenStream {
 pub fn derive_try_into_rows(input: TokenStream) -> TokenStream {
     itemize_derive_impl::handle_derive_try_into_rows(input.into()).into()
 }
+
+#[proc_macro]
+pub fn impl_items(input: TokenStream) -> TokenStream {
+    itemize_derive_impl::handle_impl_items(input.into()).into()
+}
+
+#[proc_macro_attribute]
+pub fn accepts(args: TokenStream, item: TokenStream) -> TokenStream {
+    itemize_derive_impl::handle_accepts(args.into(), item.into()).into()
+}
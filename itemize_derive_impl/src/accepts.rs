@@ -0,0 +1,298 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, quote};
+use syn::Token;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+
+/// Options of `#[accepts]`, currently only `crate = path`.
+struct Args {
+    krate: TokenStream,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self {
+                krate: quote! { ::itemize },
+            });
+        }
+        input.parse::<Token![crate]>()?;
+        input.parse::<Token![=]>()?;
+        let path: syn::Path = input.parse()?;
+        Ok(Self {
+            krate: path.to_token_stream(),
+        })
+    }
+}
+
+/// The annotated function, parsed with the types syn provides without its `full`
+/// feature: everything that is not rewritten stays as tokens.
+struct Function {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    /// Qualifiers before `fn`, such as `const`, `async`, `unsafe` or `extern "C"`.
+    qualifiers: TokenStream,
+    fn_token: Token![fn],
+    ident: syn::Ident,
+    generics: syn::Generics,
+    paren: syn::token::Paren,
+    params: Punctuated<Param, Token![,]>,
+    output: syn::ReturnType,
+    brace: syn::token::Brace,
+    inner_attrs: Vec<syn::Attribute>,
+    body: TokenStream,
+}
+
+impl Parse for Function {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let mut qualifiers = TokenStream::new();
+        while !input.peek(Token![fn]) {
+            if input.is_empty() {
+                return Err(input.error("expected a function"));
+            }
+            qualifiers.extend([input.parse::<TokenTree>()?]);
+        }
+        let fn_token = input.parse()?;
+        let ident = input.parse()?;
+        let mut generics: syn::Generics = input.parse()?;
+        let params;
+        let paren = syn::parenthesized!(params in input);
+        let params = params.parse_terminated(Param::parse, Token![,])?;
+        let output = input.parse()?;
+        generics.where_clause = input.parse()?;
+        let body;
+        let brace = syn::braced!(body in input);
+        Ok(Self {
+            attrs,
+            vis,
+            qualifiers,
+            fn_token,
+            ident,
+            generics,
+            paren,
+            params,
+            output,
+            brace,
+            inner_attrs: body.call(syn::Attribute::parse_inner)?,
+            body: body.parse()?,
+        })
+    }
+}
+
+/// A function parameter. Receivers such as `&self` have no separate type.
+struct Param {
+    attrs: Vec<syn::Attribute>,
+    pat: TokenStream,
+    ty: Option<syn::Type>,
+}
+
+impl Parse for Param {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let mut pat = TokenStream::new();
+        while !input.is_empty() && !input.peek(Token![,]) {
+            if input.peek(Token![::]) {
+                input.parse::<Token![::]>()?.to_tokens(&mut pat);
+            } else if input.peek(Token![:]) {
+                input.parse::<Token![:]>()?;
+                let ty = input.parse()?;
+                return Ok(Self {
+                    attrs,
+                    pat,
+                    ty: Some(ty),
+                });
+            } else {
+                pat.extend([input.parse::<TokenTree>()?]);
+            }
+        }
+        Ok(Self {
+            attrs,
+            pat,
+            ty: None,
+        })
+    }
+}
+
+impl ToTokens for Param {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for attr in &self.attrs {
+            attr.to_tokens(tokens);
+        }
+        self.pat.to_tokens(tokens);
+        if let Some(ty) = &self.ty {
+            <Token![:]>::default().to_tokens(tokens);
+            ty.to_tokens(tokens);
+        }
+    }
+}
+
+/// The identifier of a parameter bound as `ident`, `mut ident` or `ref ident`.
+fn binding(input: ParseStream) -> syn::Result<syn::Ident> {
+    input.parse::<Option<Token![ref]>>()?;
+    input.parse::<Option<Token![mut]>>()?;
+    input.parse()
+}
+
+/// How a parameter is accepted, from its `#[items]`, `#[rows]`, `#[try_items(E)]` or
+/// `#[try_rows(E)]` attribute.
+enum Kind {
+    Items,
+    Rows,
+    TryItems(syn::Type),
+    TryRows(syn::Type),
+}
+
+impl Kind {
+    fn try_from(attr: &syn::Attribute) -> syn::Result<Option<Self>> {
+        let path = attr.path();
+        Ok(if path.is_ident("items") {
+            attr.meta.require_path_only()?;
+            Some(Kind::Items)
+        } else if path.is_ident("rows") {
+            attr.meta.require_path_only()?;
+            Some(Kind::Rows)
+        } else if path.is_ident("try_items") {
+            Some(Kind::TryItems(attr.parse_args()?))
+        } else if path.is_ident("try_rows") {
+            Some(Kind::TryRows(attr.parse_args()?))
+        } else {
+            None
+        })
+    }
+}
+
+/// Rewrites the annotated parameters of a function into `impl IntoItems`-style bounds
+/// and collects them back into the declared type at the start of the body.
+pub(crate) fn generate(args: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let Args { krate } = syn::parse2(args)?;
+    let mut function: Function = syn::parse2(item)?;
+    let mut collects = Vec::new();
+
+    for param in &mut function.params {
+        let mut kind = None;
+        let mut error = None;
+        param.attrs.retain(|attr| match Kind::try_from(attr) {
+            Ok(None) => true,
+            Ok(Some(found)) => {
+                if kind.replace(found).is_some() {
+                    error.get_or_insert(syn::Error::new_spanned(
+                        attr,
+                        "a parameter accepts only one of `items`, `rows`, `try_items`, `try_rows`",
+                    ));
+                }
+                false
+            }
+            Err(e) => {
+                error.get_or_insert(e);
+                false
+            }
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+        let Some(kind) = kind else {
+            continue;
+        };
+
+        let (Ok(ident), Some(ty)) = (binding.parse2(param.pat.clone()), &mut param.ty) else {
+            return Err(syn::Error::new_spanned(
+                &param.pat,
+                "accepted parameters must be bound to a plain identifier",
+            ));
+        };
+        let pat = std::mem::replace(&mut param.pat, ident.to_token_stream());
+        let item = match kind {
+            Kind::Items | Kind::TryItems(_) => item_type(ty)?,
+            Kind::Rows | Kind::TryRows(_) => item_type(item_type(ty)?)?,
+        };
+
+        let (bound, collect) = match &kind {
+            Kind::Items => (
+                quote! { #krate::IntoItems<#item> },
+                quote! {
+                    ::core::iter::Iterator::collect(#krate::IntoItems::into_items(#ident))
+                },
+            ),
+            Kind::Rows => (
+                quote! { #krate::IntoRows<#item> },
+                quote! {
+                    ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+                        #krate::IntoRows::into_rows(#ident),
+                        ::core::iter::Iterator::collect,
+                    ))
+                },
+            ),
+            Kind::TryItems(error) => (
+                quote! { #krate::TryIntoItems<#item, #error> },
+                quote! {
+                    ::core::iter::Iterator::collect::<::core::result::Result<#ty, #error>>(
+                        #krate::TryIntoItems::try_into_items(#ident),
+                    )?
+                },
+            ),
+            Kind::TryRows(error) => (
+                quote! { #krate::TryIntoRows<#item, #error> },
+                quote! {
+                    ::core::iter::Iterator::collect::<::core::result::Result<#ty, #error>>(
+                        ::core::iter::Iterator::map(
+                            #krate::TryIntoRows::try_into_rows(#ident),
+                            ::core::iter::Iterator::collect,
+                        ),
+                    )?
+                },
+            ),
+        };
+        collects.push(quote! { let #pat: #ty = #collect; });
+        *ty = syn::parse_quote! { impl #bound };
+    }
+
+    let Function {
+        attrs,
+        vis,
+        qualifiers,
+        fn_token,
+        ident,
+        generics,
+        paren,
+        params,
+        output,
+        brace,
+        inner_attrs,
+        body,
+    } = &function;
+    let where_clause = &generics.where_clause;
+    let mut tokens = quote! { #(#attrs)* #vis #qualifiers #fn_token #ident #generics };
+    paren.surround(&mut tokens, |tokens| params.to_tokens(tokens));
+    quote! { #output #where_clause }.to_tokens(&mut tokens);
+    brace.surround(&mut tokens, |tokens| {
+        quote! {
+            #(#inner_attrs)*
+            #(#collects)*
+            #body
+        }
+        .to_tokens(tokens)
+    });
+    Ok(tokens)
+}
+
+/// The single type argument of a collection type such as `Vec<T>`.
+fn item_type(ty: &syn::Type) -> syn::Result<&syn::Type> {
+    if let syn::Type::Path(path) = ty
+        && let Some(segment) = path.path.segments.last()
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+    {
+        let mut types = args.args.iter().filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        });
+        if let (Some(item), None) = (types.next(), types.next()) {
+            return Ok(item);
+        }
+    }
+    Err(syn::Error::new_spanned(
+        ty,
+        "expected a collection with a single item type, like `Vec<T>`",
+    ))
+}
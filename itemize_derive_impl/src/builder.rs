@@ -0,0 +1,162 @@
+use std::ops::RangeInclusive;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, quote};
+
+use crate::context::{CollectionType, Context, TraitKind};
+use crate::traits;
+
+/// Generates itemize impls for a target type from another proc macro, with the same
+/// options as `#[items_from(...)]`.
+///
+/// ```
+/// use itemize_derive_impl::{CollectionType, ImplBuilder};
+/// use quote::quote;
+///
+/// let tokens = ImplBuilder::new(syn::parse_quote!(UserId), syn::Generics::default())
+///     .types([quote!(u64), quote!(&'a str via String)])
+///     .tuples(1..=4)
+///     .collections([CollectionType::Vec, CollectionType::Slice])
+///     .crate_path(syn::parse_quote!(::my_crate::itemize))
+///     .into_items();
+/// assert!(!tokens.is_empty());
+/// ```
+///
+/// Invalid options expand to a `compile_error!` like the derives do. The target must be
+/// defined in the crate the output ends up in.
+#[derive(Clone)]
+pub struct ImplBuilder {
+    target: syn::Type,
+    generics: syn::Generics,
+    types: Vec<TokenStream>,
+    tuples: Option<TokenStream>,
+    collections: Vec<CollectionType>,
+    error_type: Option<syn::Type>,
+    borrow: Option<TokenStream>,
+    crate_path: Option<syn::Path>,
+}
+
+impl ImplBuilder {
+    /// Starts a builder for `target`, with `generics` declared on every generated impl.
+    pub fn new(target: syn::Type, generics: syn::Generics) -> Self {
+        Self {
+            target,
+            generics,
+            types: Vec::new(),
+            tuples: None,
+            collections: Vec::new(),
+            error_type: None,
+            borrow: None,
+            crate_path: None,
+        }
+    }
+
+    /// Source types to accept, in the syntax of `types(...)`, so `via` and `for<...>`
+    /// sources are written as tokens.
+    pub fn types<T: ToTokens>(mut self, types: impl IntoIterator<Item = T>) -> Self {
+        self.types
+            .extend(types.into_iter().map(|ty| ty.to_token_stream()));
+        self
+    }
+
+    /// Tuple sizes to accept, as in `tuples(1..=4)`.
+    pub fn tuples(mut self, sizes: RangeInclusive<usize>) -> Self {
+        let start = Literal::usize_unsuffixed(*sizes.start());
+        let end = Literal::usize_unsuffixed(*sizes.end());
+        self.tuples = Some(quote! { #start..=#end });
+        self
+    }
+
+    /// Links the `LeanTuple` impls of `itemize` instead, as in `tuples(lean)`.
+    pub fn lean_tuples(mut self) -> Self {
+        self.tuples = Some(quote! { lean });
+        self
+    }
+
+    /// Collection inputs to accept, as in `collections(vec, slice, array)`.
+    pub fn collections(mut self, collections: impl IntoIterator<Item = CollectionType>) -> Self {
+        self.collections.extend(collections);
+        self
+    }
+
+    /// Error type the `TryInto*` impls are locked to; ignored by `IntoItems` and `IntoRows`.
+    pub fn error_type(mut self, error_type: syn::Type) -> Self {
+        self.error_type = Some(error_type);
+        self
+    }
+
+    /// Accepts borrowed sources by cloning them, as `cloned` does.
+    pub fn cloned(mut self) -> Self {
+        self.borrow = Some(quote! { cloned });
+        self
+    }
+
+    /// Accepts borrowed sources by copying them, as `copied` does.
+    pub fn copied(mut self) -> Self {
+        self.borrow = Some(quote! { copied });
+        self
+    }
+
+    /// Path to `itemize` in the generated code, `::itemize` by default.
+    pub fn crate_path(mut self, path: syn::Path) -> Self {
+        self.crate_path = Some(path);
+        self
+    }
+
+    /// Generates the `IntoItems` impls for the target.
+    pub fn into_items(&self) -> TokenStream {
+        self.generate(TraitKind::IntoItems, traits::into_items::generate)
+    }
+
+    /// Generates the `TryIntoItems` impls for the target.
+    pub fn try_into_items(&self) -> TokenStream {
+        self.generate(TraitKind::TryIntoItems, traits::try_into_items::generate)
+    }
+
+    /// Generates the `IntoRows` impls for the target.
+    pub fn into_rows(&self) -> TokenStream {
+        self.generate(TraitKind::IntoRows, traits::into_rows::generate)
+    }
+
+    /// Generates the `TryIntoRows` impls for the target.
+    pub fn try_into_rows(&self) -> TokenStream {
+        self.generate(TraitKind::TryIntoRows, traits::try_into_rows::generate)
+    }
+
+    fn generate(&self, kind: TraitKind, generate: fn(&Context<'_>) -> TokenStream) -> TokenStream {
+        let args = self.args(kind);
+        match Context::try_new_foreign(&self.target, args, &self.generics, &[kind]) {
+            Ok(contexts) => contexts.iter().map(generate).collect(),
+            Err(e) => e.to_compile_error(),
+        }
+    }
+
+    /// The options as `#[items_from(...)]` would spell them, so they go through the
+    /// same validation.
+    fn args(&self, kind: TraitKind) -> TokenStream {
+        let mut args = Vec::new();
+        if !self.types.is_empty() {
+            let types = &self.types;
+            args.push(quote! { types(#(#types),*) });
+        }
+        if let Some(sizes) = &self.tuples {
+            args.push(quote! { tuples(#sizes) });
+        }
+        if !self.collections.is_empty() {
+            let collections = &self.collections;
+            args.push(quote! { collections(#(#collections),*) });
+        }
+        if let Some(error_type) = &self.error_type
+            && kind.fallible()
+        {
+            args.push(quote! { error_type(#error_type) });
+        }
+        if let Some(borrow) = &self.borrow {
+            args.push(borrow.clone());
+        }
+        if let Some(path) = &self.crate_path {
+            args.push(quote! { crate = #path });
+        }
+        quote! { #(#args),* }
+    }
+}
@@ -1,21 +1,130 @@
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use proc_macro2::TokenStream;
-use quote::{ToTokens, quote};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::parse::Parser;
-use syn::{Attribute, DeriveInput, Meta, MetaList};
+use syn::spanned::Spanned;
+use syn::{Attribute, DeriveInput, Meta, MetaList, MetaNameValue};
 
-use crate::util::GenericList;
+use crate::util::{GenericList, InjectedNames, collect_names, respan, substitute};
+
+/// The trait a context generates impls for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraitKind {
+    IntoItems,
+    TryIntoItems,
+    IntoRows,
+    TryIntoRows,
+}
+
+impl TraitKind {
+    /// Whether the trait carries an error type, so attributes with different
+    /// `error_type`s generate separate impls.
+    pub(crate) fn fallible(self) -> bool {
+        matches!(self, TraitKind::TryIntoItems | TraitKind::TryIntoRows)
+    }
+
+    /// Whether the trait reads the `rows(...)` section rather than `items(...)`.
+    fn rows(self) -> bool {
+        matches!(self, TraitKind::IntoRows | TraitKind::TryIntoRows)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            TraitKind::IntoItems => "IntoItems",
+            TraitKind::TryIntoItems => "TryIntoItems",
+            TraitKind::IntoRows => "IntoRows",
+            TraitKind::TryIntoRows => "TryIntoRows",
+        }
+    }
+
+    /// Name of the inherent const through which a derive of the trait tells the other
+    /// derives of the target that it is present.
+    pub(crate) fn evidence(self) -> syn::Ident {
+        let name = match self {
+            TraitKind::IntoItems => "__ITEMIZE_INTO_ITEMS",
+            TraitKind::TryIntoItems => "__ITEMIZE_TRY_INTO_ITEMS",
+            TraitKind::IntoRows => "__ITEMIZE_INTO_ROWS",
+            TraitKind::TryIntoRows => "__ITEMIZE_TRY_INTO_ROWS",
+        };
+        syn::Ident::new(name, Span::call_site())
+    }
+}
+
+/// An option read by only some of the traits, which has no effect unless one of them
+/// is generated for the target.
+pub(crate) struct OptionUse {
+    pub(crate) name: &'static str,
+    pub(crate) span: Span,
+    pub(crate) readers: &'static [TraitKind],
+}
+
+impl OptionUse {
+    /// Rejects the option when none of `kinds` reads it.
+    fn check(&self, kinds: &[TraitKind]) -> syn::Result<()> {
+        if self.readers.iter().any(|reader| kinds.contains(reader)) {
+            return Ok(());
+        }
+        let readers = self
+            .readers
+            .iter()
+            .map(|reader| format!("`{}`", reader.name()))
+            .collect::<Vec<_>>()
+            .join(" or ");
+        Err(syn::Error::new(
+            self.span,
+            format!("`{}` has no effect without {readers}", self.name),
+        ))
+    }
+}
 
 pub(crate) struct Context<'a> {
     pub(crate) attributes: Attributes,
     pub(crate) where_predicates: Option<Vec<TokenStream>>,
-    pub(crate) generics: &'a syn::Generics,
+    /// Parameters of the impls besides those collected from sources and the target;
+    /// empty for a `target = ...` instantiation.
+    pub(crate) generics: Cow<'a, syn::Generics>,
     pub(crate) concrete: TokenStream,
+    /// Path to the `itemize` crate used by the generated code.
+    pub(crate) krate: TokenStream,
+    pub(crate) names: InjectedNames,
+    pub(crate) wrappers: Vec<Wrapper<'a>>,
+    /// Arguments substituted for the target's type and const parameters when
+    /// generating for a `target = ...` instantiation.
+    substitutions: HashMap<String, TokenStream>,
+}
+
+/// A single field that declared sources are converted into, either the field of a
+/// `#[items_from(wrap)]` struct or of an enum variant carrying its own `types(...)`.
+pub(crate) struct Wrapper<'a> {
+    pub(crate) ident: &'a syn::Ident,
+    pub(crate) variant: Option<&'a syn::Ident>,
+    pub(crate) field: &'a syn::Field,
+    pub(crate) types: Vec<SourceType>,
+}
+
+impl Wrapper<'_> {
+    /// Expression constructing the target from the field `value`, valid in impls for
+    /// other types as well.
+    pub(crate) fn construct(&self, value: TokenStream) -> TokenStream {
+        let ident = self.ident;
+        let path = match self.variant {
+            Some(variant) => quote! { #ident::#variant },
+            None => quote! { #ident },
+        };
+        match &self.field.ident {
+            Some(field) => quote! { #path { #field: #value } },
+            None => quote! { #path(#value) },
+        }
+    }
 }
 
 impl<'a> Context<'a> {
-    pub(crate) fn try_new(ast: &'a DeriveInput) -> syn::Result<Self> {
+    /// Builds one context per set of impls of `kind` to generate. For a fallible trait,
+    /// every distinct `error_type` across the `#[items_from]` attributes gets its own
+    /// context; otherwise all attributes are merged into one.
+    pub(crate) fn try_new_all(ast: &'a DeriveInput, kind: TraitKind) -> syn::Result<Vec<Self>> {
         // Validate that this is being used on a struct or enum
         match &ast.data {
             syn::Data::Struct(_) | syn::Data::Enum(_) => {}
@@ -38,48 +147,542 @@ impl<'a> Context<'a> {
                 .map(|pred| pred.to_token_stream())
                 .collect::<Vec<_>>()
         });
-        Ok(Self {
-            attributes: Attributes::try_from(&ast.attrs)?,
-            generics: &ast.generics,
-            where_predicates,
-            concrete,
-        })
+        let mut groups = Attributes::try_from_all(&ast.attrs, kind)?;
+        for target in groups
+            .iter()
+            .filter_map(|attributes| attributes.target.as_ref())
+        {
+            target_arguments(ident, &ast.generics, target)?;
+        }
+
+        let variants = match &ast.data {
+            syn::Data::Enum(_) if groups.iter().any(|attributes| attributes.wrap) => {
+                return Err(err(
+                    ident,
+                    "`wrap` is only supported on structs; place `types(...)` on enum variants instead",
+                ));
+            }
+            syn::Data::Enum(data) => Wrapper::collect_variants(data)?,
+            _ => Vec::new(),
+        };
+        for (_, sources) in &variants {
+            for attributes in &mut groups {
+                attributes.types.extend(sources.iter().cloned());
+            }
+        }
+        for attributes in &groups {
+            attributes.validate()?;
+            if attributes.target.is_none() {
+                attributes.check_source_params(&ast.generics)?;
+            }
+        }
+
+        // The impls linked by `tuples(lean)` cover every error type of a target, so only
+        // the first lean group links them.
+        let mut linked = HashSet::new();
+        for attributes in &mut groups {
+            if matches!(attributes.tuples, Some(Tuples::Lean))
+                && !linked.insert(attributes.target_key())
+            {
+                attributes.tuples = None;
+            }
+        }
+
+        let krate = crate_path(&groups)?;
+        let names = injected_names(&ast.generics, &groups);
+
+        // Wrapped sources are converted inline by the fallible traits, so every group for
+        // a target knows the sources wrapped by any of them.
+        let mut contexts = Vec::new();
+        for idx in 0..groups.len() {
+            let key = groups[idx].target_key();
+            let same_target = || {
+                groups
+                    .iter()
+                    .filter(|attributes| attributes.target_key() == key)
+            };
+
+            let mut wrappers = Vec::new();
+            for (variant, sources) in &variants {
+                wrappers.push(Wrapper::new(
+                    ident,
+                    Some(&variant.ident),
+                    &variant.fields,
+                    sources,
+                )?);
+            }
+            if let syn::Data::Struct(data) = &ast.data
+                && same_target().any(|attributes| attributes.wrap)
+            {
+                let sources = same_target()
+                    .filter(|attributes| attributes.wrap)
+                    .flat_map(|attributes| attributes.types.iter().cloned())
+                    .collect::<Vec<_>>();
+                wrappers.push(Wrapper::new(ident, None, &data.fields, &sources)?);
+            }
+
+            let (generics, concrete, where_predicates, substitutions) = match &groups[idx].target {
+                Some(target) => (
+                    Cow::Owned(syn::Generics::default()),
+                    quote! { #target },
+                    None,
+                    target_arguments(ident, &ast.generics, target)?,
+                ),
+                None => (
+                    Cow::Borrowed(&ast.generics),
+                    concrete.clone(),
+                    where_predicates.clone(),
+                    HashMap::new(),
+                ),
+            };
+            contexts.push((
+                wrappers,
+                generics,
+                concrete,
+                where_predicates,
+                substitutions,
+            ));
+        }
+
+        Ok(groups
+            .into_iter()
+            .zip(contexts)
+            .map(
+                |(attributes, (wrappers, generics, concrete, where_predicates, substitutions))| {
+                    Self {
+                        attributes,
+                        generics,
+                        where_predicates,
+                        concrete,
+                        krate: krate.clone(),
+                        names: names.clone(),
+                        wrappers,
+                        substitutions,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    /// Builds the contexts for `impl_items!`, one per trait in `kinds`, generating for
+    /// a `target` type that cannot carry a derive. Options that add conversions into the
+    /// target itself are rejected since the impls would not be allowed outside the
+    /// target's crate.
+    pub(crate) fn try_new_foreign(
+        target: &syn::Type,
+        args: TokenStream,
+        generics: &'a syn::Generics,
+        kinds: &[TraitKind],
+    ) -> syn::Result<Vec<Self>> {
+        let attributes = Attributes::try_from_tokens(target.span(), args)?;
+        if attributes.wrap {
+            return Err(err(
+                target,
+                "`wrap` is not supported by `impl_items!`; `as Name` wraps the target in a local newtype",
+            ));
+        }
+        if let Some(ty) = &attributes.target {
+            return Err(err(
+                ty,
+                "`target` is not supported by `impl_items!`; name the type before `:`",
+            ));
+        }
+        let krate = crate_path(std::slice::from_ref(&attributes))?;
+        let names = injected_names(generics, std::slice::from_ref(&attributes));
+        let where_predicates = generics.where_clause.as_ref().map(|clause| {
+            clause
+                .predicates
+                .iter()
+                .map(|pred| pred.to_token_stream())
+                .collect::<Vec<_>>()
+        });
+        kinds
+            .iter()
+            .map(|&kind| {
+                let mut attributes = attributes.clone().for_trait(kind);
+                attributes.validate()?;
+                attributes.check_source_params(generics)?;
+                attributes.target = Some(target.clone());
+                Ok(Self {
+                    attributes,
+                    generics: Cow::Borrowed(generics),
+                    where_predicates: where_predicates.clone(),
+                    concrete: quote! { #target },
+                    krate: krate.clone(),
+                    names: names.clone(),
+                    wrappers: Vec::new(),
+                    substitutions: HashMap::new(),
+                })
+            })
+            .collect()
+    }
+
+    /// The type `ty` as seen from this context's target, with the target's parameters
+    /// replaced by the arguments of a `target = ...` instantiation.
+    pub(crate) fn resolve(&self, ty: &syn::Type) -> TokenStream {
+        substitute(ty.to_token_stream(), &self.substitutions)
     }
 
     pub(crate) fn generics(&self) -> GenericList {
-        GenericList::new().with_generics(self.generics)
+        let generics = GenericList::new().with_generics(&self.generics);
+        match &self.attributes.target {
+            Some(target) => generics.with_lifetimes_from_type(target),
+            None => generics,
+        }
+    }
+
+    /// The wrapper whose field `source` is converted into, if it is wrapped.
+    pub(crate) fn wrapper(&self, source: &SourceType) -> Option<&Wrapper<'a>> {
+        let key = source.direct().key();
+        self.wrappers
+            .iter()
+            .find(|wrapper| wrapper.types.iter().any(|ty| ty.key() == key))
+    }
+
+    /// Whether some sources only reach the target through a `via` type, so tuple and
+    /// collection elements go through the single-value impls rather than the target's
+    /// `From` or `TryFrom`.
+    pub(crate) fn sourced(&self) -> bool {
+        self.attributes
+            .types
+            .iter()
+            .any(|source| source.via.is_some())
+    }
+
+    /// Like [`Self::sourced`] for `TryIntoItems`, whose single-value impls also convert
+    /// wrapped and `parse(...)` sources inline rather than through `TryFrom` impls on the
+    /// target.
+    pub(crate) fn try_sourced(&self) -> bool {
+        self.sourced() || !self.wrappers.is_empty() || !self.attributes.parse.is_empty()
+    }
+
+    /// Whether tuple elements go through their single-value impls, which borrowed
+    /// elements also need, so the target gets one for itself.
+    pub(crate) fn single_elements(&self) -> bool {
+        self.attributes.borrow.is_some() || self.sourced()
+    }
+
+    /// Like [`Self::single_elements`] for `TryIntoItems`.
+    pub(crate) fn try_single_elements(&self) -> bool {
+        self.attributes.borrow.is_some() || self.try_sourced()
     }
 
     pub(crate) fn error_generics(&self) -> GenericList {
         match &self.attributes.error_type {
             Some(_) => self.generics(),
-            None => self.generics().with_types(self.error_ty()),
+            None => self.generics().with_types([&self.names.error]),
         }
     }
 
     pub(crate) fn error_ty(&self) -> TokenStream {
         match &self.attributes.error_type {
             Some(ty) => quote! { #ty },
-            None => quote! { E },
+            None => self.names.error.to_token_stream(),
         }
     }
 }
 
+impl<'a> Wrapper<'a> {
+    /// Wraps into the single field of `fields`, converting from the type each source
+    /// reaches the target through (its `via` type if any).
+    fn new(
+        ident: &'a syn::Ident,
+        variant: Option<&'a syn::Ident>,
+        fields: &'a syn::Fields,
+        sources: &[SourceType],
+    ) -> syn::Result<Self> {
+        let field = match fields {
+            syn::Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => {
+                return Err(match variant {
+                    Some(variant) => err(
+                        variant,
+                        "`#[items_from]` on a variant requires exactly one field",
+                    ),
+                    None => err(fields, "`wrap` requires a struct with exactly one field"),
+                });
+            }
+        };
+
+        let mut seen = HashSet::new();
+        let types = sources
+            .iter()
+            .map(SourceType::direct)
+            .filter(|source| seen.insert(source.key()))
+            .collect();
+
+        Ok(Self {
+            ident,
+            variant,
+            field,
+            types,
+        })
+    }
+
+    fn collect_variants(
+        data: &'a syn::DataEnum,
+    ) -> syn::Result<Vec<(&'a syn::Variant, Vec<SourceType>)>> {
+        let mut variants = Vec::new();
+        for variant in &data.variants {
+            if let Some(sources) = Attributes::try_variant_types(&variant.attrs)? {
+                variants.push((variant, sources));
+            }
+        }
+        Ok(variants)
+    }
+}
+
+/// A declared source type, optionally converted into the target through an
+/// intermediate type, e.g. `types(&'a str via String)`, and optionally generic over
+/// its own parameters, e.g. `types(for<T: Display> Box<T>)`.
+#[derive(Clone)]
+pub(crate) struct SourceType {
+    pub ty: syn::Type,
+    pub via: Option<syn::Type>,
+    /// Parameters declared with `for<...>`, added to the generated impl generics.
+    pub generics: Vec<syn::GenericParam>,
+}
+
+impl SourceType {
+    const VIA_IDENT: &str = "via";
+
+    /// Identity of this source, used to drop duplicates declared by several attributes.
+    fn key(&self) -> String {
+        let (ty, generics) = (&self.ty, &self.generics);
+        match &self.via {
+            Some(via) => quote! { for<#(#generics),*> #ty via #via }.to_string(),
+            None => quote! { for<#(#generics),*> #ty }.to_string(),
+        }
+    }
+
+    /// The source the target is converted from directly, i.e. its `via` type if any.
+    pub fn direct(&self) -> Self {
+        Self {
+            ty: self.via.clone().unwrap_or_else(|| self.ty.clone()),
+            via: None,
+            generics: self.generics.clone(),
+        }
+    }
+
+    /// Bounds required to convert `value` of this source into `target` with `From`.
+    pub fn conversion_bounds(&self, target: &TokenStream) -> TokenStream {
+        let ty = &self.ty;
+        let span = ty.span();
+        let target = respan(target, span);
+        match &self.via {
+            Some(via) => quote_spanned! {span=>
+                #via: ::core::convert::From<#ty>,
+                #target: ::core::convert::From<#via>
+            },
+            None => quote_spanned! {span=> #target: ::core::convert::From<#ty> },
+        }
+    }
+
+    /// Expression converting `value` of this source into `target` with `From`.
+    pub fn conversion_expr(&self, target: &TokenStream, value: TokenStream) -> TokenStream {
+        let ty = &self.ty;
+        match &self.via {
+            Some(via) => quote! {
+                <#target as ::core::convert::From<#via>>::from(<#via as ::core::convert::From<#ty>>::from(#value))
+            },
+            None => quote! { <#target as ::core::convert::From<#ty>>::from(#value) },
+        }
+    }
+
+    /// Bounds required to convert this source into `target` with `TryFrom`, merging
+    /// the error of every step into `error_ty`.
+    pub fn try_conversion_bounds(
+        &self,
+        target: &TokenStream,
+        error_ty: &TokenStream,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        let span = ty.span();
+        let target = respan(target, span);
+        match &self.via {
+            Some(via) => quote_spanned! {span=>
+                #via: ::core::convert::TryFrom<#ty>,
+                <#via as ::core::convert::TryFrom<#ty>>::Error: ::core::convert::Into<#error_ty>,
+                #target: ::core::convert::TryFrom<#via>,
+                <#target as ::core::convert::TryFrom<#via>>::Error: ::core::convert::Into<#error_ty>
+            },
+            None => quote_spanned! {span=>
+                #target: ::core::convert::TryFrom<#ty>,
+                <#target as ::core::convert::TryFrom<#ty>>::Error: ::core::convert::Into<#error_ty>
+            },
+        }
+    }
+
+    /// Expression converting `value` of this source into `Result<target, error_ty>`.
+    pub fn try_conversion_expr(&self, target: &TokenStream, value: TokenStream) -> TokenStream {
+        let ty = &self.ty;
+        match &self.via {
+            Some(via) => quote! {
+                <#via as ::core::convert::TryFrom<#ty>>::try_from(#value)
+                    .map_err(::core::convert::Into::into)
+                    .and_then(|value| <#target as ::core::convert::TryFrom<#via>>::try_from(value).map_err(::core::convert::Into::into))
+            },
+            None => quote! {
+                <#target as ::core::convert::TryFrom<#ty>>::try_from(#value).map_err(::core::convert::Into::into)
+            },
+        }
+    }
+}
+
+impl SourceType {
+    /// The `for<...>` type parameter `ty` is, possibly behind references, `Box` or `Pin`,
+    /// which orphan rules treat as the parameter itself.
+    fn uncovered_param<'g>(
+        ty: &syn::Type,
+        generics: &'g [syn::GenericParam],
+    ) -> Option<&'g syn::Ident> {
+        match ty {
+            syn::Type::Reference(reference) => Self::uncovered_param(&reference.elem, generics),
+            syn::Type::Paren(paren) => Self::uncovered_param(&paren.elem, generics),
+            syn::Type::Group(group) => Self::uncovered_param(&group.elem, generics),
+            syn::Type::Path(path) if path.qself.is_none() => {
+                let segment = path.path.segments.last()?;
+                match &segment.arguments {
+                    syn::PathArguments::None if path.path.segments.len() == 1 => {
+                        generics.iter().find_map(|param| match param {
+                            syn::GenericParam::Type(param) if param.ident == segment.ident => {
+                                Some(&param.ident)
+                            }
+                            _ => None,
+                        })
+                    }
+                    syn::PathArguments::AngleBracketed(args)
+                        if segment.ident == "Box" || segment.ident == "Pin" =>
+                    {
+                        match args.args.first()? {
+                            syn::GenericArgument::Type(ty) => Self::uncovered_param(ty, generics),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<syn::Type> for SourceType {
+    fn from(ty: syn::Type) -> Self {
+        Self {
+            ty,
+            via: None,
+            generics: Vec::new(),
+        }
+    }
+}
+
+impl syn::parse::Parse for SourceType {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut generics = Vec::new();
+        if input.peek(syn::Token![for]) {
+            input.parse::<syn::Token![for]>()?;
+            input.parse::<syn::Token![<]>()?;
+            while !input.peek(syn::Token![>]) {
+                generics.push(input.parse()?);
+                if !input.peek(syn::Token![>]) {
+                    input.parse::<syn::Token![,]>()?;
+                }
+            }
+            input.parse::<syn::Token![>]>()?;
+        }
+        let ty = input.parse()?;
+        let via = if input.peek(syn::Ident) {
+            let ident: syn::Ident = input.parse()?;
+            if ident != Self::VIA_IDENT {
+                return Err(err(ident, "expected `via` or `,` after source type"));
+            }
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { ty, via, generics })
+    }
+}
+
 /// Example:
 /// ```ignore
 /// #[items_from(types(String, char), tuples, collections(vec, slice, array))]
+/// #[items_from(types(&'a str via String, u8 via u32))] // convert through an intermediate type
+/// #[items_from(types(for<T: Display> Option<T>))] // generic source with its own parameters
 /// #[items_from(tuples(1..=4))]  // explicit range
 /// #[items_from(tuples(2..=4))]  // excludes 1-tuples
 /// #[items_from(tuples(4))]      // shorthand for 1..=4
 /// #[items_from(tuples(exact(4)))] // only size 4
+/// #[items_from(tuples(lean))]   // `LeanTuple` inputs shared from `itemize`, sized by its features
 /// #[items_from(error_type(MyError))]
+/// #[items_from(cloned)]         // borrowed sources clone into owned `From<T>`
+/// #[items_from(copied)]         // borrowed sources copy into owned `From<T>`
+/// #[items_from(wrap)]           // generate `From<Source>` wrapping into the single field
+/// #[items_from(parse(&'a str, String))] // fallible conversion through `FromStr`
+/// #[items_from(crate = my_facade::itemize)] // path to `itemize` when re-exported or renamed
+/// #[items_from(target = Foo<String>, types(char))] // impls for one instantiation only
+/// #[items_from(tuples(4), rows(tuples(2)))] // per-trait overrides in `items(...)`/`rows(...)`
 /// ```
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub(crate) struct Attributes {
-    pub types: Vec<syn::Type>,
-    pub tuples: Option<TupleRange>,
-    pub collections: HashSet<CollectionType>,
+    /// Span of the `#[items_from]` attribute these options were parsed from.
+    pub span: Option<Span>,
+    pub types: Vec<SourceType>,
+    pub tuples: Option<Tuples>,
+    /// Span of the `tuples` option, which diagnostics about tuple impls point at.
+    pub tuples_span: Option<Span>,
+    /// Enabled collections with the span of the ident that enabled each.
+    pub collections: BTreeMap<CollectionType, Span>,
     pub error_type: Option<syn::Type>,
+    pub borrow: Option<BorrowMode>,
+    pub wrap: bool,
+    /// Span of the `wrap` option, when given in this attribute.
+    pub wrap_span: Option<Span>,
+    pub parse: Vec<syn::Type>,
+    /// Span of the `parse` option, when given in this attribute.
+    pub parse_span: Option<Span>,
+    pub crate_path: Option<syn::Path>,
+    /// Concrete instantiation of the target to generate impls for.
+    pub target: Option<syn::Type>,
+    /// Options of the `items(...)` section, overriding the shared ones for
+    /// `IntoItems` and `TryIntoItems`.
+    pub items: Option<Box<Attributes>>,
+    /// Options of the `rows(...)` section, overriding the shared ones for `IntoRows`
+    /// and `TryIntoRows`.
+    pub rows: Option<Box<Attributes>>,
+}
+
+/// A single `#[items_from]` parameter. `target = Type` is parsed on its own since a
+/// generic type is not a valid meta value.
+enum Param {
+    Target(syn::Type),
+    Meta(Meta),
+}
+
+impl syn::parse::Parse for Param {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.peek2(syn::Token![=]) {
+            let fork = input.fork();
+            if fork.parse::<syn::Ident>()? == Attributes::TARGET_IDENT {
+                input.parse::<syn::Ident>()?;
+                input.parse::<syn::Token![=]>()?;
+                return input.parse().map(Param::Target);
+            }
+        }
+        input.parse().map(Param::Meta)
+    }
+}
+
+/// Tuple inputs enabled by `tuples(...)`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Tuples {
+    /// Tuple impls generated on the target for each size in the range.
+    Range(TupleRange),
+    /// `tuples(lean)`: the impls of `itemize::LeanTuple`, linked to the target by a
+    /// marker impl, for the sizes enabled by its `tuples-*` features.
+    Lean,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -94,13 +697,56 @@ impl TupleRange {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub(crate) enum CollectionType {
+/// Collection inputs enabled by `collections(...)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CollectionType {
     Vec,
     Slice,
     Array,
 }
 
+/// How borrowed sources (`&[T]`, `&Vec<T>`, `&T`) are turned into owned values
+/// before going through the owned `From<T>`/`TryFrom<T>` impls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BorrowMode {
+    Cloned,
+    Copied,
+}
+
+impl BorrowMode {
+    /// Iterator adapter wrapping a borrowing iterator, e.g. `::core::iter::Cloned`.
+    pub fn adapter(self) -> TokenStream {
+        match self {
+            BorrowMode::Cloned => quote! { ::core::iter::Cloned },
+            BorrowMode::Copied => quote! { ::core::iter::Copied },
+        }
+    }
+
+    /// Method producing [`Self::adapter`] from a borrowing iterator.
+    pub fn method(self) -> TokenStream {
+        match self {
+            BorrowMode::Cloned => quote! { cloned },
+            BorrowMode::Copied => quote! { copied },
+        }
+    }
+
+    /// Bound the borrowed element type must satisfy.
+    pub fn bound(self) -> TokenStream {
+        match self {
+            BorrowMode::Cloned => quote! { ::core::clone::Clone },
+            BorrowMode::Copied => quote! { ::core::marker::Copy },
+        }
+    }
+
+    /// Expression turning a reference into an owned value.
+    pub fn to_owned(self, expr: TokenStream) -> TokenStream {
+        match self {
+            BorrowMode::Cloned => quote! { ::core::clone::Clone::clone(#expr) },
+            BorrowMode::Copied => quote! { *#expr },
+        }
+    }
+}
+
 impl TryFrom<syn::Ident> for CollectionType {
     type Error = syn::Error;
     fn try_from(ident: syn::Ident) -> Result<Self, Self::Error> {
@@ -119,6 +765,17 @@ impl TryFrom<syn::Ident> for CollectionType {
     }
 }
 
+impl ToTokens for CollectionType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = match self {
+            CollectionType::Vec => "vec",
+            CollectionType::Slice => "slice",
+            CollectionType::Array => "array",
+        };
+        syn::Ident::new(name, Span::call_site()).to_tokens(tokens);
+    }
+}
+
 impl Attributes {
     const PATH_IDENT: &str = "items_from";
     const TYPES_IDENT: &str = "types";
@@ -126,10 +783,502 @@ impl Attributes {
     const DEFAULT_TUPLES: TupleRange = TupleRange { start: 1, end: 6 };
     const COLLECTIONS_IDENT: &str = "collections";
     const ERROR_TYPE_IDENT: &str = "error_type";
+    const CLONED_IDENT: &str = "cloned";
+    const COPIED_IDENT: &str = "copied";
+    const WRAP_IDENT: &str = "wrap";
+    const PARSE_IDENT: &str = "parse";
+    const CRATE_IDENT: &str = "crate";
+    const TARGET_IDENT: &str = "target";
+    const ITEMS_IDENT: &str = "items";
+    const ROWS_IDENT: &str = "rows";
+
+    /// Parses every `#[items_from]` attribute into its own set of options, with the
+    /// `items(...)` or `rows(...)` section for `kind` applied. Attributes naming
+    /// different `target` instantiations always stay separate; for fallible traits, so
+    /// do attributes locked to different error types. Attributes sharing both are
+    /// merged.
+    fn try_from_all(attrs: &[Attribute], kind: TraitKind) -> syn::Result<Vec<Self>> {
+        let mut groups: Vec<Self> = Vec::new();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(Self::PATH_IDENT))
+        {
+            let attributes = Self::try_from(attr)?.for_trait(kind);
+            let (target, error) = (attributes.target_key(), attributes.error_key());
+            match groups.iter_mut().find(|group| {
+                group.target_key() == target && (!kind.fallible() || group.error_key() == error)
+            }) {
+                Some(group) => group.merge(attributes)?,
+                None => groups.push(attributes),
+            }
+        }
+
+        if let Some(generic) = groups.iter().find(|group| {
+            group.error_type.is_none()
+                && groups
+                    .iter()
+                    .filter(|other| other.target_key() == group.target_key())
+                    .count()
+                    > 1
+        }) {
+            return Err(syn::Error::new(
+                generic.span(),
+                "an `#[items_from]` without `error_type` overlaps the impls of every other error type; add `error_type(...)` to each attribute",
+            ));
+        }
+        if groups.is_empty() {
+            groups.push(Attributes::default());
+        }
+        Ok(groups)
+    }
+
+    fn try_from(attr: &Attribute) -> syn::Result<Self> {
+        Self::try_from_tokens(attr.span(), attr.meta.require_list()?.tokens.clone())
+    }
+
+    /// Parses the comma-separated options inside an `#[items_from(...)]`.
+    fn try_from_tokens(span: Span, tokens: TokenStream) -> syn::Result<Self> {
+        Self::try_from_section(span, tokens, false)
+    }
+
+    /// Parses options at the top level of an attribute or, with `nested`, inside an
+    /// `items(...)` or `rows(...)` section, where only per-trait options are allowed.
+    fn try_from_section(span: Span, tokens: TokenStream, nested: bool) -> syn::Result<Self> {
+        let mut attributes = Attributes {
+            span: Some(span),
+            ..Attributes::default()
+        };
+
+        let params = syn::punctuated::Punctuated::<Param, syn::Token![,]>::parse_terminated
+            .parse2(tokens)?;
+        for param in params {
+            let meta = match param {
+                // Handle `target = Type` syntax
+                Param::Target(target) if nested => {
+                    return Err(err(target, Self::SHARED_ONLY));
+                }
+                Param::Target(target) => {
+                    attributes.target = Some(target);
+                    continue;
+                }
+                Param::Meta(meta) => meta,
+            };
+            if nested
+                && [
+                    Self::WRAP_IDENT,
+                    Self::PARSE_IDENT,
+                    Self::CRATE_IDENT,
+                    Self::ITEMS_IDENT,
+                    Self::ROWS_IDENT,
+                ]
+                .iter()
+                .any(|ident| meta.path().is_ident(ident))
+            {
+                return Err(err(meta.path(), Self::SHARED_ONLY));
+            }
+            match &meta {
+                // Handle `items(...)` and `rows(...)` sections
+                Meta::List(MetaList { path, tokens, .. }) if path.is_ident(Self::ITEMS_IDENT) => {
+                    let section = Self::try_from_section(meta.span(), tokens.clone(), true)?;
+                    attributes.items = Some(Box::new(section));
+                }
+                Meta::List(MetaList { path, tokens, .. }) if path.is_ident(Self::ROWS_IDENT) => {
+                    let section = Self::try_from_section(meta.span(), tokens.clone(), true)?;
+                    attributes.rows = Some(Box::new(section));
+                }
+
+                // Handle `types(...)` syntax
+                Meta::List(MetaList { path, tokens, .. }) if path.is_ident(Self::TYPES_IDENT) => {
+                    attributes.types = Self::parse_types(tokens)?;
+                }
+
+                // Handle `tuples` or `tuples(N)` syntax
+                Meta::Path(path) if path.is_ident(Self::TUPLES_IDENT) => {
+                    attributes.tuples = Some(Tuples::Range(Self::DEFAULT_TUPLES));
+                    attributes.tuples_span = Some(path.span());
+                }
+                Meta::List(MetaList { path, tokens, .. }) if path.is_ident(Self::TUPLES_IDENT) => {
+                    attributes.tuples = Some(Self::parse_tuples(tokens)?);
+                    attributes.tuples_span = Some(meta.span());
+                }
+
+                // Handle `collections(...)` syntax
+                Meta::List(MetaList { path, tokens, .. })
+                    if path.is_ident(Self::COLLECTIONS_IDENT) =>
+                {
+                    attributes
+                        .collections
+                        .extend(Self::parse_collections(tokens)?);
+                }
+
+                // Handle `error_type(...)` syntax
+                Meta::List(MetaList { path, tokens, .. })
+                    if path.is_ident(Self::ERROR_TYPE_IDENT) =>
+                {
+                    attributes.error_type = Some(Self::parse_error_type(tokens)?);
+                }
+
+                // Handle `cloned` / `copied` syntax
+                Meta::Path(path) if path.is_ident(Self::CLONED_IDENT) => {
+                    attributes.set_borrow(path, BorrowMode::Cloned)?;
+                }
+                Meta::Path(path) if path.is_ident(Self::COPIED_IDENT) => {
+                    attributes.set_borrow(path, BorrowMode::Copied)?;
+                }
+
+                // Handle `parse(...)` syntax
+                Meta::List(MetaList { path, tokens, .. }) if path.is_ident(Self::PARSE_IDENT) => {
+                    attributes.parse = Self::parse_parse_types(tokens)?;
+                    attributes.parse_span = Some(path.span());
+                }
+
+                // Handle `crate = path` syntax
+                Meta::NameValue(MetaNameValue { path, value, .. })
+                    if path.is_ident(Self::CRATE_IDENT) =>
+                {
+                    attributes.crate_path = Some(Self::parse_crate_path(value)?);
+                }
+
+                // Handle `wrap` syntax
+                Meta::Path(path) if path.is_ident(Self::WRAP_IDENT) => {
+                    attributes.wrap = true;
+                    attributes.wrap_span = Some(path.span());
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &meta,
+                        "unknown attribute parameter; supported parameters are: types, tuples, collections, error_type, cloned, copied, wrap, parse, crate, target, items, rows",
+                    ));
+                }
+            }
+        }
+
+        Ok(attributes)
+    }
+
+    /// Rejects sources declared twice or whose impls would overlap those generated
+    /// for another option, which would otherwise surface as coherence errors.
+    fn validate(&self) -> syn::Result<()> {
+        let mut seen = HashSet::new();
+        for ty in self
+            .types
+            .iter()
+            .map(|source| &source.ty)
+            .chain(&self.parse)
+        {
+            if !seen.insert(ty.to_token_stream().to_string()) {
+                return Err(err(
+                    ty,
+                    "source type is declared more than once, across `types(...)`, `parse(...)` or enum variants",
+                ));
+            }
+            if let Some(option) = self.overlapping_option(ty) {
+                return Err(err(
+                    ty,
+                    format!("source type overlaps the impls generated by `{option}`"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects `for<...>` parameters named like a parameter of the target's `generics`,
+    /// which the generated impls declare alongside them.
+    fn check_source_params(&self, generics: &syn::Generics) -> syn::Result<()> {
+        let name = |param: &syn::GenericParam| match param {
+            syn::GenericParam::Type(param) => param.ident.to_string(),
+            syn::GenericParam::Lifetime(param) => param.lifetime.to_string(),
+            syn::GenericParam::Const(param) => param.ident.to_string(),
+        };
+        let target = generics.params.iter().map(name).collect::<HashSet<_>>();
+        for param in self.types.iter().flat_map(|source| &source.generics) {
+            let name = name(param);
+            if target.contains(&name) {
+                return Err(err(
+                    param,
+                    format!(
+                        "`for<...>` parameter `{name}` shadows a parameter of the target; rename it"
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The option whose generated impls already cover the source type `ty`, if any.
+    fn overlapping_option(&self, ty: &syn::Type) -> Option<String> {
+        let is_vec = |ty: &syn::Type| match ty {
+            syn::Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Vec"),
+            _ => false,
+        };
+        let borrow = match self.borrow {
+            Some(BorrowMode::Cloned) => Self::CLONED_IDENT,
+            Some(BorrowMode::Copied) => Self::COPIED_IDENT,
+            None => "",
+        };
+        match ty {
+            syn::Type::Tuple(tuple) => match self.tuples? {
+                Tuples::Range(range) => range
+                    .iter()
+                    .contains(&tuple.elems.len())
+                    .then(|| format!("tuples({}..={})", range.start, range.end)),
+                Tuples::Lean => Some("tuples(lean)".to_string()),
+            },
+            syn::Type::Array(_) if self.collections.contains_key(&CollectionType::Array) => {
+                Some("collections(array)".to_string())
+            }
+            ty if is_vec(ty) && self.collections.contains_key(&CollectionType::Vec) => {
+                Some("collections(vec)".to_string())
+            }
+            // `&Vec<T>` is generated both for the owned source and for borrowed slices.
+            ty if is_vec(ty)
+                && self.borrow.is_some()
+                && self.collections.contains_key(&CollectionType::Slice) =>
+            {
+                Some(format!("collections(slice)` with `{borrow}"))
+            }
+            syn::Type::Reference(reference) => match &*reference.elem {
+                syn::Type::Slice(_) if self.collections.contains_key(&CollectionType::Slice) => {
+                    Some("collections(slice)".to_string())
+                }
+                elem if is_vec(elem)
+                    && self.borrow.is_some()
+                    && self.collections.contains_key(&CollectionType::Slice) =>
+                {
+                    Some(format!("collections(slice)` with `{borrow}"))
+                }
+                elem if self.borrow.is_some()
+                    && self.owned_types().any(|source| {
+                        source.ty.to_token_stream().to_string()
+                            == elem.to_token_stream().to_string()
+                    }) =>
+                {
+                    Some(borrow.to_string())
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 
-    fn try_from(attrs: &[Attribute]) -> syn::Result<Self> {
-        let mut attributes = Attributes::default();
+    fn span(&self) -> Span {
+        self.span.unwrap_or_else(Span::call_site)
+    }
+
+    const SHARED_ONLY: &str =
+        "this option is shared by every trait and cannot be set inside `items(...)` or `rows(...)`";
+
+    /// Applies the `items(...)` or `rows(...)` section read by `kind` over the shared
+    /// options; options the section sets replace the shared ones.
+    fn for_trait(mut self, kind: TraitKind) -> Self {
+        let (items, rows) = (self.items.take(), self.rows.take());
+        let Some(section) = (if kind.rows() { rows } else { items }) else {
+            return self;
+        };
+        let section = *section;
+        if !section.types.is_empty() {
+            self.types = section.types;
+        }
+        if section.tuples.is_some() {
+            self.tuples = section.tuples;
+            self.tuples_span = section.tuples_span;
+        }
+        if !section.collections.is_empty() {
+            self.collections = section.collections;
+        }
+        if section.error_type.is_some() {
+            self.error_type = section.error_type;
+        }
+        if section.borrow.is_some() {
+            self.borrow = section.borrow;
+        }
+        self
+    }
+
+    /// The options of this attribute, sections included, that only some traits read.
+    fn option_uses(&self) -> Vec<OptionUse> {
+        use TraitKind::*;
+
+        let error_type = |section: &Option<Box<Attributes>>, readers| {
+            section
+                .as_ref()
+                .and_then(|section| section.error_type.as_ref())
+                .map(|ty| OptionUse {
+                    name: Self::ERROR_TYPE_IDENT,
+                    span: ty.span(),
+                    readers,
+                })
+        };
+        let uses = [
+            self.error_type.as_ref().map(|ty| OptionUse {
+                name: Self::ERROR_TYPE_IDENT,
+                span: ty.span(),
+                readers: &[TryIntoItems, TryIntoRows],
+            }),
+            error_type(&self.items, &[TryIntoItems]),
+            error_type(&self.rows, &[TryIntoRows]),
+            self.wrap_span.map(|span| OptionUse {
+                name: Self::WRAP_IDENT,
+                span,
+                readers: &[IntoItems, TryIntoItems],
+            }),
+            self.parse_span.map(|span| OptionUse {
+                name: Self::PARSE_IDENT,
+                span,
+                readers: &[TryIntoItems],
+            }),
+        ];
+        uses.into_iter().flatten().collect()
+    }
+
+    /// Span diagnostics about the impls for `collection` point at.
+    pub fn collection_span(&self, collection: &CollectionType) -> Span {
+        self.collections
+            .get(collection)
+            .copied()
+            .unwrap_or_else(Span::call_site)
+    }
+
+    /// Span diagnostics about tuple impls point at.
+    pub fn tuples_span(&self) -> Span {
+        self.tuples_span.unwrap_or_else(Span::call_site)
+    }
+
+    fn target_key(&self) -> Option<String> {
+        self.target
+            .as_ref()
+            .map(|ty| ty.to_token_stream().to_string())
+    }
+
+    fn error_key(&self) -> Option<String> {
+        self.error_type
+            .as_ref()
+            .map(|ty| ty.to_token_stream().to_string())
+    }
 
+    /// Merges the options of another attribute sharing this one's impls, rejecting
+    /// options that cannot be combined.
+    fn merge(&mut self, other: Self) -> syn::Result<()> {
+        let span = other.span();
+        let mut seen: HashSet<String> = self.types.iter().map(SourceType::key).collect();
+        self.types.extend(
+            other
+                .types
+                .into_iter()
+                .filter(|source| seen.insert(source.key())),
+        );
+
+        let mut seen: HashSet<String> = self
+            .parse
+            .iter()
+            .map(|ty| ty.to_token_stream().to_string())
+            .collect();
+        self.parse.extend(
+            other
+                .parse
+                .into_iter()
+                .filter(|ty| seen.insert(ty.to_token_stream().to_string())),
+        );
+
+        self.tuples = match (self.tuples, other.tuples) {
+            (Some(Tuples::Range(a)), Some(Tuples::Range(b)))
+                if a.start <= b.end + 1 && b.start <= a.end + 1 =>
+            {
+                Some(Tuples::Range(TupleRange {
+                    start: a.start.min(b.start),
+                    end: a.end.max(b.end),
+                }))
+            }
+            (Some(Tuples::Range(a)), Some(Tuples::Range(b))) => {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "tuple ranges `{}..={}` and `{}..={}` cannot be merged; declare one contiguous range",
+                        a.start, a.end, b.start, b.end
+                    ),
+                ));
+            }
+            (Some(Tuples::Lean), Some(Tuples::Range(_)))
+            | (Some(Tuples::Range(_)), Some(Tuples::Lean)) => {
+                return Err(syn::Error::new(
+                    span,
+                    "`tuples(lean)` cannot be merged with a tuple range",
+                ));
+            }
+            (a, b) => a.or(b),
+        };
+
+        for (collection, span) in other.collections {
+            self.collections.entry(collection).or_insert(span);
+        }
+        self.tuples_span = self.tuples_span.or(other.tuples_span);
+
+        self.borrow = match (self.borrow, other.borrow) {
+            (Some(a), Some(b)) if a != b => {
+                return Err(syn::Error::new(
+                    span,
+                    "`cloned` and `copied` cannot be used together",
+                ));
+            }
+            (a, b) => a.or(b),
+        };
+
+        self.error_type = self.error_type.take().or(other.error_type);
+        self.crate_path = merge_crate_paths(self.crate_path.take(), other.crate_path)?;
+        self.wrap |= other.wrap;
+        Ok(())
+    }
+
+    /// Names used by the declared types, which injected generic parameters must avoid.
+    fn collect_names(&self, names: &mut HashSet<String>) {
+        for source in &self.types {
+            collect_names(source.ty.to_token_stream(), names);
+            collect_names(source.via.to_token_stream(), names);
+            collect_names(
+                source
+                    .generics
+                    .iter()
+                    .map(ToTokens::to_token_stream)
+                    .collect(),
+                names,
+            );
+        }
+        collect_names(
+            self.parse.iter().map(ToTokens::to_token_stream).collect(),
+            names,
+        );
+        collect_names(self.error_type.to_token_stream(), names);
+        collect_names(self.target.to_token_stream(), names);
+    }
+
+    /// Declared source types that are not references, i.e. those a borrowed
+    /// `&Source` impl can be generated for in `cloned`/`copied` mode.
+    pub(crate) fn owned_types(&self) -> impl Iterator<Item = &SourceType> {
+        self.types
+            .iter()
+            .filter(|source| !matches!(source.ty, syn::Type::Reference(_)))
+    }
+
+    fn set_borrow(&mut self, path: &syn::Path, mode: BorrowMode) -> syn::Result<()> {
+        match self.borrow {
+            Some(existing) if existing != mode => {
+                Err(err(path, "`cloned` and `copied` cannot be used together"))
+            }
+            _ => {
+                self.borrow = Some(mode);
+                Ok(())
+            }
+        }
+    }
+
+    /// Parses the `#[items_from(types(...))]` attributes placed on an enum variant,
+    /// returning `None` when the variant has none.
+    fn try_variant_types(attrs: &[Attribute]) -> syn::Result<Option<Vec<SourceType>>> {
+        let mut types = None;
         for attr in attrs {
             if attr.path().is_ident(Self::PATH_IDENT) {
                 let meta_items = attr.parse_args_with(
@@ -137,62 +1286,85 @@ impl Attributes {
                 )?;
                 for meta in meta_items {
                     match &meta {
-                        // Handle `types(...)` syntax
                         Meta::List(MetaList { path, tokens, .. })
                             if path.is_ident(Self::TYPES_IDENT) =>
                         {
-                            attributes.types = Self::parse_types(tokens)?;
-                        }
-
-                        // Handle `tuples` or `tuples(N)` syntax
-                        Meta::Path(path) if path.is_ident(Self::TUPLES_IDENT) => {
-                            attributes.tuples = Some(Self::DEFAULT_TUPLES);
-                        }
-                        Meta::List(MetaList { path, tokens, .. })
-                            if path.is_ident(Self::TUPLES_IDENT) =>
-                        {
-                            attributes.tuples = Some(Self::parse_tuples(tokens)?);
-                        }
-
-                        // Handle `collections(...)` syntax
-                        Meta::List(MetaList { path, tokens, .. })
-                            if path.is_ident(Self::COLLECTIONS_IDENT) =>
-                        {
-                            attributes
-                                .collections
-                                .extend(Self::parse_collections(tokens)?);
-                        }
-
-                        // Handle `error_type(...)` syntax
-                        Meta::List(MetaList { path, tokens, .. })
-                            if path.is_ident(Self::ERROR_TYPE_IDENT) =>
-                        {
-                            attributes.error_type = Some(Self::parse_error_type(tokens)?);
+                            types
+                                .get_or_insert_with(Vec::new)
+                                .extend(Self::parse_types(tokens)?);
                         }
                         _ => {
                             return Err(syn::Error::new_spanned(
                                 &meta,
-                                "unknown attribute parameter; supported parameters are: types, tuples, collections, error_type",
+                                "unknown variant attribute parameter; only `types` is supported on enum variants",
                             ));
                         }
                     }
                 }
             }
         }
+        Ok(types)
+    }
 
-        Ok(attributes)
+    fn parse_types(tokens: &TokenStream) -> syn::Result<Vec<SourceType>> {
+        let types: syn::punctuated::Punctuated<SourceType, syn::Token![,]> =
+            syn::punctuated::Punctuated::parse_terminated
+                .parse2(tokens.clone())
+                .map_err(|_| err(tokens, "failed to parse types"))?;
+        let mut seen = HashSet::new();
+        for source in &types {
+            if !seen.insert(source.key()) {
+                return Err(err(&source.ty, "type is listed more than once"));
+            }
+            if let Some(param) = SourceType::uncovered_param(&source.ty, &source.generics) {
+                return Err(err(
+                    &source.ty,
+                    format!(
+                        "a bare `for<...>` parameter cannot be a source type: the impl would cover every type `{param}`, which orphan rules reject; use it inside a type of its own, e.g. `Option<{param}>`"
+                    ),
+                ));
+            }
+        }
+        Ok(types.into_iter().collect())
     }
 
-    fn parse_types(tokens: &TokenStream) -> syn::Result<Vec<syn::Type>> {
+    fn parse_parse_types(tokens: &TokenStream) -> syn::Result<Vec<syn::Type>> {
         let types: syn::punctuated::Punctuated<syn::Type, syn::Token![,]> =
             syn::punctuated::Punctuated::parse_terminated
                 .parse2(tokens.clone())
-                .map_err(|_| err(tokens, "failed to parse types"))?;
+                .map_err(|_| err(tokens, "failed to parse types for `parse`"))?;
+        let mut seen = HashSet::new();
+        for ty in &types {
+            if !seen.insert(ty.to_token_stream().to_string()) {
+                return Err(err(ty, "type is listed more than once"));
+            }
+        }
         Ok(types.into_iter().collect())
     }
 
-    fn parse_tuples(tokens: &TokenStream) -> syn::Result<TupleRange> {
-        syn::parse::Parser::parse2(Self::parse_tuple_range, tokens.clone())
+    fn parse_crate_path(value: &syn::Expr) -> syn::Result<syn::Path> {
+        match value {
+            syn::Expr::Path(syn::ExprPath {
+                path, qself: None, ..
+            }) => Ok(path.clone()),
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) => lit.parse(),
+            _ => Err(err(
+                value,
+                "expected path for `crate`, e.g. `crate = my_facade::itemize`",
+            )),
+        }
+    }
+
+    fn parse_tuples(tokens: &TokenStream) -> syn::Result<Tuples> {
+        if let Ok(ident) = syn::parse2::<syn::Ident>(tokens.clone())
+            && ident == "lean"
+        {
+            return Ok(Tuples::Lean);
+        }
+        syn::parse::Parser::parse2(Self::parse_tuple_range, tokens.clone()).map(Tuples::Range)
     }
 
     fn parse_tuple_range(input: syn::parse::ParseStream) -> syn::Result<TupleRange> {
@@ -204,9 +1376,12 @@ impl Attributes {
                 syn::parenthesized!(content in input);
                 let lit: syn::LitInt = content.parse()?;
                 let n: usize = lit.base10_parse()?;
+                if n == 0 {
+                    return Err(err(lit, "tuple sizes start at 1"));
+                }
                 return Ok(TupleRange { start: n, end: n });
             }
-            return Err(syn::Error::new_spanned(ident, "expected `exact`"));
+            return Err(syn::Error::new_spanned(ident, "expected `exact` or `lean`"));
         }
 
         // Try parsing as range (1..=4) or shorthand (4)
@@ -219,10 +1394,21 @@ impl Attributes {
             input.parse::<syn::Token![=]>()?;
             let end: syn::LitInt = input.parse()?;
             let end_val: usize = end.base10_parse()?;
+            if start_val == 0 {
+                return Err(err(start, "tuple sizes start at 1"));
+            }
+            if start_val > end_val {
+                return Err(err(
+                    quote! { #start..=#end },
+                    format!("empty tuple range; did you mean `{end_val}..={start_val}`?"),
+                ));
+            }
             Ok(TupleRange {
                 start: start_val,
                 end: end_val,
             })
+        } else if start_val == 0 {
+            Err(err(start, "tuple sizes start at 1"))
         } else {
             // Shorthand: N means 1..=N
             Ok(TupleRange {
@@ -232,13 +1418,16 @@ impl Attributes {
         }
     }
 
-    fn parse_collections(tokens: &TokenStream) -> syn::Result<Vec<CollectionType>> {
+    fn parse_collections(tokens: &TokenStream) -> syn::Result<Vec<(CollectionType, Span)>> {
         let idents: syn::punctuated::Punctuated<syn::Ident, syn::Token![,]> =
             syn::punctuated::Punctuated::parse_terminated.parse2(tokens.clone())?;
 
         idents
             .into_iter()
-            .map(CollectionType::try_from)
+            .map(|ident| {
+                let span = ident.span();
+                CollectionType::try_from(ident).map(|collection| (collection, span))
+            })
             .collect::<Result<Vec<_>, _>>()
     }
 
@@ -247,6 +1436,115 @@ impl Attributes {
     }
 }
 
+/// The options across the `#[items_from]` attributes of `ast` that only some traits
+/// read.
+pub(crate) fn option_uses(ast: &DeriveInput) -> syn::Result<Vec<OptionUse>> {
+    let mut uses = Vec::new();
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(Attributes::PATH_IDENT))
+    {
+        uses.extend(Attributes::try_from(attr)?.option_uses());
+    }
+    Ok(uses)
+}
+
+/// Rejects the options among the `impl_items!` `args` that none of `kinds` reads.
+pub(crate) fn check_option_uses(args: TokenStream, kinds: &[TraitKind]) -> syn::Result<()> {
+    let attributes = Attributes::try_from_tokens(Span::call_site(), args)?;
+    for option in attributes.option_uses() {
+        option.check(kinds)?;
+    }
+    Ok(())
+}
+
+/// Path to `itemize` shared by every group, from their `crate = path` options.
+fn crate_path(groups: &[Attributes]) -> syn::Result<TokenStream> {
+    let mut crate_path = None;
+    for attributes in groups {
+        crate_path = merge_crate_paths(crate_path, attributes.crate_path.clone())?;
+    }
+    Ok(match crate_path {
+        Some(path) => quote! { #path },
+        None => quote! { ::itemize },
+    })
+}
+
+/// The `crate` path given by either of two sets of options, which must agree when
+/// both give one.
+fn merge_crate_paths(a: Option<syn::Path>, b: Option<syn::Path>) -> syn::Result<Option<syn::Path>> {
+    match (a, b) {
+        (Some(a), Some(b)) if quote!(#a).to_string() != quote!(#b).to_string() => Err(err(
+            b,
+            "conflicting `crate` paths across `#[items_from]` attributes",
+        )),
+        (a, b) => Ok(a.or(b)),
+    }
+}
+
+/// Injected parameter names avoiding those in `generics` and in every group.
+fn injected_names(generics: &syn::Generics, groups: &[Attributes]) -> InjectedNames {
+    let mut taken = HashSet::new();
+    // `Generics` prints without its where clause, which may name types of its own.
+    collect_names(generics.to_token_stream(), &mut taken);
+    collect_names(generics.where_clause.to_token_stream(), &mut taken);
+    for attributes in groups {
+        attributes.collect_names(&mut taken);
+    }
+    InjectedNames::new(&taken)
+}
+
+/// Maps the type and const parameters of the deriving type to the arguments of a
+/// `target = ...` instantiation of it.
+fn target_arguments(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    target: &syn::Type,
+) -> syn::Result<HashMap<String, TokenStream>> {
+    let segment = match target {
+        syn::Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+        _ => None,
+    };
+    let Some(segment) = segment.filter(|segment| &segment.ident == ident) else {
+        return Err(err(
+            target,
+            format!("`target` must be an instantiation of `{ident}`"),
+        ));
+    };
+    let arguments = match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter(|arg| !matches!(arg, syn::GenericArgument::Lifetime(_)))
+            .collect::<Vec<_>>(),
+        _ => Vec::new(),
+    };
+    let params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) => Some(&param.ident),
+            syn::GenericParam::Const(param) => Some(&param.ident),
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if params.len() != arguments.len() {
+        return Err(err(
+            target,
+            format!(
+                "`target` must give all {} type and const arguments of `{ident}`",
+                params.len()
+            ),
+        ));
+    }
+    Ok(params
+        .into_iter()
+        .zip(arguments)
+        .map(|(param, arg)| (param.to_string(), arg.to_token_stream()))
+        .collect())
+}
+
 fn err<T: ToTokens, U: std::fmt::Display>(tokens: T, message: U) -> syn::Error {
     syn::Error::new_spanned(tokens, message)
 }
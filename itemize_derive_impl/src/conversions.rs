@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+
+use crate::context::{Context, SourceType, Wrapper};
+
+/// Generates `From<Source>` for every source wrapped into a single field,
+/// converting through the field's own `From<Source>`.
+pub(crate) fn generate_from(ctx: &Context<'_>) -> TokenStream {
+    let impls = wrapper_sources(ctx).map(|(wrapper, source)| {
+        let field_ty = ctx.resolve(&wrapper.field.ty);
+        let generics = ctx
+            .generics()
+            .with_generics_from_source(source)
+            .to_token_stream();
+        let source = &source.ty;
+        let value = wrapper
+            .construct(quote! { <#field_ty as ::core::convert::From<#source>>::from(value) });
+        let item = &ctx.concrete;
+        let predicates = ctx.where_predicates.iter().flatten();
+
+        quote! {
+            impl #generics ::core::convert::From<#source> for #item
+            where
+                #(#predicates,)*
+                #field_ty: ::core::convert::From<#source>
+            {
+                #[inline]
+                fn from(value: #source) -> Self {
+                    #value
+                }
+            }
+        }
+    });
+
+    quote! { #(#impls)* }
+}
+
+fn wrapper_sources<'c>(
+    ctx: &'c Context<'_>,
+) -> impl Iterator<Item = (&'c Wrapper<'c>, &'c SourceType)> {
+    ctx.wrappers
+        .iter()
+        .flat_map(|wrapper| wrapper.types.iter().map(move |source| (wrapper, source)))
+}
@@ -1,39 +1,250 @@
 use proc_macro2::TokenStream;
 use syn::DeriveInput;
+use syn::parse::{Parse, ParseStream};
 
 use crate::context::Context;
 
+pub use crate::builder::ImplBuilder;
+pub use crate::context::{CollectionType, TraitKind};
+
+mod accepts;
+mod builder;
 mod context;
+mod conversions;
 mod traits;
 mod util;
 
 pub fn handle_derive_into_items(input: TokenStream) -> TokenStream {
-    handle_generate(input, traits::into_items::generate)
+    handle_generate(input, TraitKind::IntoItems, traits::into_items::generate)
 }
 
 pub fn handle_derive_into_rows(input: TokenStream) -> TokenStream {
-    handle_generate(input, traits::into_rows::generate)
+    handle_generate(input, TraitKind::IntoRows, traits::into_rows::generate)
 }
 
 pub fn handle_derive_try_into_items(input: TokenStream) -> TokenStream {
-    handle_generate(input, traits::try_into_items::generate)
+    handle_generate(
+        input,
+        TraitKind::TryIntoItems,
+        traits::try_into_items::generate,
+    )
 }
 
 pub fn handle_derive_try_into_rows(input: TokenStream) -> TokenStream {
-    handle_generate(input, traits::try_into_rows::generate)
+    handle_generate(
+        input,
+        TraitKind::TryIntoRows,
+        traits::try_into_rows::generate,
+    )
+}
+
+/// Expands the derive of `kind` on `input` into pretty-printed source, to inspect or
+/// snapshot the generated impls without `cargo expand`. Requires the `expand` feature.
+#[cfg(feature = "expand")]
+pub fn expand(kind: TraitKind, input: TokenStream) -> String {
+    let tokens = match kind {
+        TraitKind::IntoItems => handle_derive_into_items(input),
+        TraitKind::TryIntoItems => handle_derive_try_into_items(input),
+        TraitKind::IntoRows => handle_derive_into_rows(input),
+        TraitKind::TryIntoRows => handle_derive_try_into_rows(input),
+    };
+    match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => tokens.to_string(),
+    }
 }
 
 fn handle_generate(
     input: TokenStream,
+    kind: TraitKind,
     generate: impl Fn(&Context<'_>) -> TokenStream,
 ) -> TokenStream {
     let ast = match syn::parse2::<DeriveInput>(input) {
         Ok(ast) => ast,
         Err(e) => return e.to_compile_error(),
     };
-    let context = match Context::try_new(&ast) {
-        Ok(ctx) => ctx,
+    let contexts = match Context::try_new_all(&ast, kind) {
+        Ok(contexts) => contexts,
+        Err(e) => return e.to_compile_error(),
+    };
+    let mut output = match traits::option_checks(&ast, kind, &contexts[0].krate) {
+        Ok(checks) => checks,
+        Err(e) => return e.to_compile_error(),
+    };
+    output.extend(contexts.iter().map(generate));
+    output
+}
+
+/// Rewrites `#[items]`, `#[rows]`, `#[try_items(E)]` and `#[try_rows(E)]` parameters
+/// of a function into the matching `impl` bounds, collecting them in the body.
+pub fn handle_accepts(args: TokenStream, item: TokenStream) -> TokenStream {
+    match accepts::generate(args, item.clone()) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            let error = e.to_compile_error();
+            quote::quote! { #error #item }
+        }
+    }
+}
+
+/// Generates the listed traits for a type that cannot carry a derive, from input of the
+/// form `IntoItems, TryIntoItems for<T: Clone> Target<T>: types(...), tuples(4)`. With
+/// `as Name` after the target, the impls are generated for a local newtype wrapping it
+/// instead, so the target may be defined in another crate.
+pub fn handle_impl_items(input: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<ImplItemsInput>(input) {
+        Ok(input) => input,
         Err(e) => return e.to_compile_error(),
     };
-    generate(&context)
+    let mut kinds = Vec::new();
+    let mut generators = Vec::new();
+    for trait_ in &input.traits {
+        let (kind, generate): (_, fn(&Context<'_>) -> TokenStream) =
+            match trait_.to_string().as_str() {
+                "IntoItems" => (TraitKind::IntoItems, traits::into_items::generate),
+                "TryIntoItems" => (TraitKind::TryIntoItems, traits::try_into_items::generate),
+                "IntoRows" => (TraitKind::IntoRows, traits::into_rows::generate),
+                "TryIntoRows" => (TraitKind::TryIntoRows, traits::try_into_rows::generate),
+                _ => {
+                    return syn::Error::new_spanned(
+                        trait_,
+                        "expected one of `IntoItems`, `TryIntoItems`, `IntoRows`, `TryIntoRows`",
+                    )
+                    .to_compile_error();
+                }
+            };
+        kinds.push(kind);
+        generators.push(generate);
+    }
+    if let Err(e) = context::check_option_uses(input.args.clone(), &kinds) {
+        return e.to_compile_error();
+    }
+    if let Some(newtype) = &input.newtype {
+        return newtype.generate(&input, &kinds, &generators);
+    }
+    if util::foreign_type(&input.target) {
+        return syn::Error::new_spanned(
+            &input.target,
+            "the target is defined outside this crate, so orphan rules reject impls for it; add `as Name` to generate them for a local newtype wrapping it",
+        )
+        .to_compile_error();
+    }
+    match Context::try_new_foreign(&input.target, input.args, &input.generics, &kinds) {
+        Ok(contexts) => generators
+            .iter()
+            .zip(&contexts)
+            .map(|(generate, ctx)| generate(ctx))
+            .collect(),
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+struct ImplItemsInput {
+    traits: Vec<syn::Ident>,
+    generics: syn::Generics,
+    target: syn::Type,
+    newtype: Option<Newtype>,
+    args: TokenStream,
+}
+
+impl Parse for ImplItemsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut traits = vec![input.parse()?];
+        while !input.peek(syn::Token![for]) {
+            input.parse::<syn::Token![,]>()?;
+            traits.push(input.parse()?);
+        }
+        input.parse::<syn::Token![for]>()?;
+        let generics = if input.peek(syn::Token![<]) {
+            input.parse()?
+        } else {
+            syn::Generics::default()
+        };
+        let target = input.parse()?;
+        let newtype = if input.peek(syn::Token![as]) {
+            input.parse::<syn::Token![as]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let args = if input.is_empty() {
+            TokenStream::new()
+        } else {
+            input.parse::<syn::Token![:]>()?;
+            input.parse()?
+        };
+        Ok(Self {
+            traits,
+            generics,
+            target,
+            newtype,
+            args,
+        })
+    }
+}
+
+/// `as #[attrs] vis Name`: a local newtype wrapping the target of `impl_items!`, for
+/// targets defined in other crates.
+struct Newtype {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    ident: syn::Ident,
+}
+
+impl Parse for Newtype {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            attrs: input.call(syn::Attribute::parse_outer)?,
+            vis: input.parse()?,
+            ident: input.parse()?,
+        })
+    }
+}
+
+impl Newtype {
+    /// Declares the newtype with `From` back into the target, and derives the traits
+    /// for it, wrapping each declared source into the field.
+    fn generate(
+        &self,
+        input: &ImplItemsInput,
+        kinds: &[TraitKind],
+        generators: &[fn(&Context<'_>) -> TokenStream],
+    ) -> TokenStream {
+        let Self { attrs, vis, ident } = self;
+        let target = &input.target;
+        let args = &input.args;
+        let generics = &input.generics;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let separator = match args.clone().into_iter().last() {
+            Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == ',' => None,
+            Some(_) => Some(quote::quote! { , }),
+            None => None,
+        };
+        let ast: DeriveInput = match syn::parse2(quote::quote! {
+            #[items_from(#args #separator wrap)]
+            #vis struct #ident #generics (pub #target) #where_clause;
+        }) {
+            Ok(ast) => ast,
+            Err(e) => return e.to_compile_error(),
+        };
+        let mut output = quote::quote! {
+            #(#attrs)*
+            #vis struct #ident #generics (pub #target) #where_clause;
+
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for #target #where_clause {
+                #[inline]
+                fn from(value: #ident #ty_generics) -> Self {
+                    value.0
+                }
+            }
+        };
+        for (&kind, generate) in kinds.iter().zip(generators) {
+            match Context::try_new_all(&ast, kind) {
+                Ok(contexts) => output.extend(contexts.iter().map(generate)),
+                Err(e) => output.extend(e.to_compile_error()),
+            }
+        }
+        output
+    }
 }
@@ -1,31 +1,92 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 
 use crate::{
-    context::{CollectionType, Context},
-    util::{const_ident, item_ident, tuple_items_impl, tuple_type_ident},
+    context::{BorrowMode, CollectionType, Context, SourceType, Tuples},
+    conversions,
+    util::{respan, tuple_items_body},
 };
 
 pub(crate) fn generate(ctx: &Context<'_>) -> TokenStream {
-    let mut configs = vec![];
+    let mut configs = vec![conversions::generate_from(ctx)];
 
-    for collection_type in &ctx.attributes.collections {
-        configs.push(Config::from_collection(ctx, collection_type).generate(ctx))
+    for collection_type in ctx.attributes.collections.keys() {
+        configs.push(
+            Config::from_collection(ctx, collection_type)
+                .spanned(ctx.attributes.collection_span(collection_type))
+                .generate(ctx),
+        )
     }
 
-    for type_ in &ctx.attributes.types {
-        configs.push(Config::from_type(ctx, type_).generate(ctx))
+    for source in &ctx.attributes.types {
+        configs.push(Config::from_type(ctx, source).generate(ctx))
     }
 
-    if let Some(tuples) = ctx.attributes.tuples {
-        for len in tuples.iter() {
-            configs.push(Config::from_tuple(ctx, len).generate(ctx))
+    // Elements going through single-value impls may also be the target itself
+    if ctx.single_elements() {
+        configs.push(Config::from_target(ctx).generate(ctx))
+    }
+
+    match ctx.attributes.tuples {
+        Some(Tuples::Range(range)) => {
+            for len in range.iter() {
+                configs.push(
+                    Config::from_tuple(ctx, len)
+                        .spanned(ctx.attributes.tuples_span())
+                        .generate(ctx),
+                )
+            }
+        }
+        Some(Tuples::Lean) => configs.push(super::lean_tuples(
+            ctx,
+            quote! { LeanItems },
+            ctx.generics(),
+        )),
+        None => {}
+    }
+
+    if let Some(mode) = ctx.attributes.borrow {
+        if ctx
+            .attributes
+            .collections
+            .contains_key(&CollectionType::Slice)
+        {
+            configs.push(Config::from_borrowed_vec(ctx, mode).generate(ctx))
+        }
+        for source in ctx.attributes.owned_types() {
+            configs.push(Config::from_borrowed_type(ctx, source, mode).generate(ctx))
         }
     }
 
     quote! { #(#configs)* }
 }
 
+/// Bound converting an element `ty` of a tuple or collection through its own
+/// single-value impl.
+fn single_bound(ctx: &Context<'_>, ty: &TokenStream) -> TokenStream {
+    let krate = &ctx.krate;
+    let item = &ctx.concrete;
+    quote! { #ty: #krate::IntoItems<#item, IntoIter = ::core::iter::Once<#item>> }
+}
+
+/// Bound on and function converting an element `ty` of a collection, through its own
+/// single-value impl when some sources only reach the target through a `via` type.
+fn element(ctx: &Context<'_>, ty: TokenStream) -> (TokenStream, TokenStream) {
+    let krate = &ctx.krate;
+    let item = &ctx.concrete;
+    if ctx.sourced() {
+        (
+            single_bound(ctx, &ty),
+            quote! { #krate::__private::single::<#item, #ty> },
+        )
+    } else {
+        (
+            quote! { #item: ::core::convert::From<#ty> },
+            quote! { #krate::__private::from::<#item, #ty> },
+        )
+    }
+}
+
 struct Config {
     concrete: TokenStream,
     associated: TokenStream,
@@ -35,85 +96,172 @@ struct Config {
 }
 
 impl Config {
-    fn from_type(ctx: &Context<'_>, type_: &syn::Type) -> Self {
+    fn from_type(ctx: &Context<'_>, source: &SourceType) -> Self {
         let item: &TokenStream = &ctx.concrete;
+        let type_ = &source.ty;
         let generics = ctx
             .generics()
-            .with_lifetimes_from_type(type_)
+            .with_generics_from_source(source)
             .to_token_stream();
+        let value = source.conversion_expr(item, quote! { self });
 
         Self {
-            associated: quote! { ::std::iter::Once<#item> },
-            body: quote! { ::std::iter::once(<#item as ::std::convert::From<#type_>>::from(self)) },
+            associated: quote! { ::core::iter::Once<#item> },
+            body: quote! { ::core::iter::once(#value) },
             concrete: quote! { #type_ },
             generics,
-            constraints: quote! { #item: ::std::convert::From<#type_> },
+            constraints: source.conversion_bounds(item),
+        }
+    }
+
+    /// The target itself as its only item.
+    fn from_target(ctx: &Context<'_>) -> Self {
+        let item = &ctx.concrete;
+
+        Self {
+            associated: quote! { ::core::iter::Once<#item> },
+            body: quote! { ::core::iter::once(self) },
+            concrete: quote! { #item },
+            generics: ctx.generics().to_token_stream(),
+            constraints: TokenStream::new(),
         }
     }
 
     fn from_collection(ctx: &Context<'_>, collection_type: &CollectionType) -> Self {
-        let item_ty = item_ident();
-        let const_ty = const_ident();
+        let lt = &ctx.names.lifetime;
+        let item_ty = &ctx.names.item;
+        let const_ty = &ctx.names.konst;
         let for_type = &ctx.concrete;
 
         let generics = ctx.generics().with_types([&item_ty]);
 
-        let map_item = map_item_from();
-
         match collection_type {
-            CollectionType::Vec => Self {
-                associated: quote! { ::std::iter::Map<::std::vec::IntoIter<#item_ty>, fn(#item_ty) -> #for_type> },
-                body: quote! { #map_item self.into_iter().map(map_item::<#for_type, #item_ty>) },
-                concrete: quote! { Vec<#item_ty> },
-                generics: generics.to_token_stream(),
-                constraints: quote! { #for_type: ::std::convert::From<#item_ty> },
-            },
-            CollectionType::Slice => Self {
-                associated: quote! { ::std::iter::Map<::std::slice::Iter<'a, #item_ty>, fn(&'a #item_ty) -> #for_type> },
-                body: quote! { #map_item self.iter().map(map_item::<#for_type, &'a #item_ty>) },
-                concrete: quote! { &'a [#item_ty] },
-                generics: generics.with_lifetimes([quote! { 'a }]).to_token_stream(),
-                constraints: quote! { #for_type: ::std::convert::From<&'a #item_ty> },
-            },
-            CollectionType::Array => Self {
-                generics: generics
-                    .with_consts([quote! { const #const_ty: usize }])
-                    .to_token_stream(),
-                associated: quote! { ::std::iter::Map<::std::array::IntoIter<#item_ty, #const_ty>, fn(#item_ty) -> #for_type> },
-                body: quote! { #map_item self.into_iter().map(map_item::<#for_type, #item_ty>) },
-                concrete: quote! { [#item_ty; #const_ty] },
-                constraints: quote! { #for_type: ::std::convert::From<#item_ty> },
+            CollectionType::Vec => {
+                let (constraints, convert) = element(ctx, quote! { #item_ty });
+                Self {
+                    associated: quote! { ::core::iter::Map<::std::vec::IntoIter<#item_ty>, fn(#item_ty) -> #for_type> },
+                    body: quote! { ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(self), #convert) },
+                    concrete: quote! { ::std::vec::Vec<#item_ty> },
+                    generics: generics.to_token_stream(),
+                    constraints,
+                }
+            }
+            CollectionType::Slice if ctx.attributes.borrow.is_some() => Self {
+                concrete: quote! { &#lt [#item_ty] },
+                ..Self::from_borrowed_vec(ctx, ctx.attributes.borrow.unwrap())
             },
+            CollectionType::Slice => {
+                let (constraints, convert) = element(ctx, quote! { &#lt #item_ty });
+                Self {
+                    associated: quote! { ::core::iter::Map<::core::slice::Iter<#lt, #item_ty>, fn(&#lt #item_ty) -> #for_type> },
+                    body: quote! { ::core::iter::Iterator::map(self.iter(), #convert) },
+                    concrete: quote! { &#lt [#item_ty] },
+                    generics: generics.with_lifetimes([quote! { #lt }]).to_token_stream(),
+                    constraints,
+                }
+            }
+            CollectionType::Array => {
+                let (constraints, convert) = element(ctx, quote! { #item_ty });
+                Self {
+                    generics: generics
+                        .with_consts([quote! { const #const_ty: ::core::primitive::usize }])
+                        .to_token_stream(),
+                    associated: quote! { ::core::iter::Map<::core::array::IntoIter<#item_ty, #const_ty>, fn(#item_ty) -> #for_type> },
+                    body: quote! { ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(self), #convert) },
+                    concrete: quote! { [#item_ty; #const_ty] },
+                    constraints,
+                }
+            }
+        }
+    }
+
+    /// `&'a Vec<T>` (and `&'a [T]`) turning each borrowed element into an owned `T`.
+    fn from_borrowed_vec(ctx: &Context<'_>, mode: BorrowMode) -> Self {
+        let lt = &ctx.names.lifetime;
+        let item_ty = &ctx.names.item;
+        let for_type = &ctx.concrete;
+        let (adapter, method, bound) = (mode.adapter(), mode.method(), mode.bound());
+        let (constraints, convert) = element(ctx, quote! { #item_ty });
+
+        Self {
+            associated: quote! { ::core::iter::Map<#adapter<::core::slice::Iter<#lt, #item_ty>>, fn(#item_ty) -> #for_type> },
+            body: quote! { ::core::iter::Iterator::map(::core::iter::Iterator::#method(self.iter()), #convert) },
+            concrete: quote! { &#lt ::std::vec::Vec<#item_ty> },
+            generics: ctx
+                .generics()
+                .with_types([&item_ty])
+                .with_lifetimes([quote! { #lt }])
+                .to_token_stream(),
+            constraints: quote! { #item_ty: #bound, #constraints },
+        }
+    }
+
+    /// `&'a Source` for a declared owned source type, going through `From<Source>`.
+    fn from_borrowed_type(ctx: &Context<'_>, source: &SourceType, mode: BorrowMode) -> Self {
+        let lt = &ctx.names.lifetime;
+        let item: &TokenStream = &ctx.concrete;
+        let type_ = &source.ty;
+        let borrowed: syn::Type = syn::parse_quote! { &#lt #type_ };
+        let generics = ctx
+            .generics()
+            .with_generics_from_source(source)
+            .with_lifetimes_from_type(&borrowed)
+            .to_token_stream();
+        let bound = mode.bound();
+        let value = source.conversion_expr(item, mode.to_owned(quote! { self }));
+        let constraints = source.conversion_bounds(item);
+
+        Self {
+            associated: quote! { ::core::iter::Once<#item> },
+            body: quote! { ::core::iter::once(#value) },
+            concrete: quote! { #borrowed },
+            generics,
+            constraints: quote! { #type_: #bound, #constraints },
         }
     }
 
     fn from_tuple(ctx: &Context<'_>, len: usize) -> Self {
+        let krate = &ctx.krate;
         let for_type: &TokenStream = &ctx.concrete;
 
-        let target = (0..len).map(tuple_type_ident).collect::<Vec<_>>();
+        let target = (0..len).map(|i| ctx.names.tuple(i)).collect::<Vec<_>>();
 
         let generics = ctx.generics().with_types(&target).to_token_stream();
 
-        let constraint = target
-            .iter()
-            .map(|target| quote! { #for_type: ::std::convert::From<#target> });
-
-        let body = tuple_items_impl(
-            len,
-            |name| quote! { <#for_type>::from(#name) },
-            |block| quote! { [#block].into_iter() },
-        );
+        // In borrow mode each element is a single declared source or a reference to one,
+        // so elements go through their own single-value impls.
+        let elements = target.iter().map(|target| quote! { #target });
+        let (constraint, convert): (Vec<_>, _) = if ctx.single_elements() {
+            (
+                elements.map(|ty| single_bound(ctx, &ty)).collect(),
+                quote! { #krate::__private::ViaItems<#for_type> },
+            )
+        } else {
+            (
+                elements
+                    .map(|ty| quote! { #for_type: ::core::convert::From<#ty> })
+                    .collect(),
+                quote! { #krate::__private::ViaFrom<#for_type> },
+            )
+        };
 
         Self {
-            associated: quote! { ::std::array::IntoIter<#for_type, #len> },
-            body,
+            associated: quote! { ::core::array::IntoIter<#for_type, #len> },
+            body: tuple_items_body(krate, len, convert),
             concrete: quote! { (#(#target,)*) },
             generics,
             constraints: quote! { #(#constraint,)* },
         }
     }
 
+    /// Points diagnostics about unsatisfied constraints at the option's `span`.
+    fn spanned(mut self, span: Span) -> Self {
+        self.constraints = respan(&self.constraints, span);
+        self
+    }
+
     fn generate(self, ctx: &Context<'_>) -> TokenStream {
+        let krate = &ctx.krate;
         let associated = self.associated;
         let body = self.body;
         let generics = self.generics;
@@ -124,7 +272,7 @@ impl Config {
         let item = &ctx.concrete;
 
         quote! {
-            impl #generics itemize::IntoItems<#item> for #concrete
+            impl #generics #krate::IntoItems<#item> for #concrete
             where
                 #(#predicates,)*
                 #constraints
@@ -138,15 +286,3 @@ impl Config {
         }
     }
 }
-
-fn map_item_from() -> TokenStream {
-    quote! {
-        #[inline]
-        fn map_item<Target, Item>(item: Item) -> Target
-        where
-            Target: ::std::convert::From<Item>,
-        {
-            Target::from(item)
-        }
-    }
-}
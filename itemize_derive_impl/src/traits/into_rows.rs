@@ -1,22 +1,36 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 
 use crate::{
-    context::{CollectionType, Context},
-    util::{const_ident, item_ident, tuple_rows_associated, tuple_rows_impl, tuple_type_ident},
+    context::{CollectionType, Context, Tuples},
+    util::{respan, shared_tuple, tuple_rows_associated, tuple_rows_impl},
 };
 
 pub(crate) fn generate(ctx: &Context<'_>) -> TokenStream {
     let mut configs = vec![];
 
-    for collection_type in &ctx.attributes.collections {
-        configs.push(Config::from_collection(ctx, collection_type).generate(ctx))
+    for collection_type in ctx.attributes.collections.keys() {
+        configs.push(
+            Config::from_collection(ctx, collection_type)
+                .spanned(ctx.attributes.collection_span(collection_type))
+                .generate(ctx),
+        )
     }
 
-    if let Some(tuples) = ctx.attributes.tuples {
-        for len in tuples.iter() {
-            configs.push(Config::from_tuple(ctx, len).generate(ctx))
+    match ctx.attributes.tuples {
+        Some(Tuples::Range(range)) => {
+            for len in range.iter() {
+                configs.push(
+                    Config::from_tuple(ctx, len)
+                        .spanned(ctx.attributes.tuples_span())
+                        .generate(ctx),
+                )
+            }
+        }
+        Some(Tuples::Lean) => {
+            configs.push(super::lean_tuples(ctx, quote! { LeanRows }, ctx.generics()))
         }
+        None => {}
     }
 
     quote! { #(#configs)* }
@@ -33,8 +47,10 @@ struct Config {
 
 impl Config {
     fn from_collection(ctx: &Context<'_>, collection_type: &CollectionType) -> Self {
-        let item_ty = item_ident();
-        let const_ty = const_ident();
+        let lt = &ctx.names.lifetime;
+        let krate = &ctx.krate;
+        let item_ty = &ctx.names.item;
+        let const_ty = &ctx.names.konst;
         let for_type = &ctx.concrete;
 
         let generics = ctx.generics().with_types([&item_ty]);
@@ -42,77 +58,98 @@ impl Config {
         match collection_type {
             CollectionType::Vec => {
                 let associated_iter =
-                    quote! { <#item_ty as itemize::IntoItems<#for_type>>::IntoIter };
+                    quote! { <#item_ty as #krate::IntoItems<#for_type>>::IntoIter };
                 let map_fn = quote! { fn(#item_ty) -> #associated_iter };
                 Self {
-                    concrete: quote! { Vec<#item_ty> },
+                    concrete: quote! { ::std::vec::Vec<#item_ty> },
                     associated_iter,
-                    associated_rows: quote! { ::std::iter::Map<::std::vec::IntoIter<#item_ty>, #map_fn> },
-                    body: quote! { self.into_iter().map(<#item_ty as itemize::IntoItems<#for_type>>::into_items) },
+                    associated_rows: quote! { ::core::iter::Map<::std::vec::IntoIter<#item_ty>, #map_fn> },
+                    body: quote! { ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(self), <#item_ty as #krate::IntoItems<#for_type>>::into_items) },
                     generics: generics.to_token_stream(),
-                    constraints: quote! { #item_ty: itemize::IntoItems<#for_type> },
+                    constraints: quote! { #item_ty: #krate::IntoItems<#for_type> },
                 }
             }
             CollectionType::Slice => {
                 let associated_iter =
-                    quote! { <&'a #item_ty as itemize::IntoItems<#for_type>>::IntoIter };
-                let map_fn = quote! { fn(&'a #item_ty) -> #associated_iter };
+                    quote! { <&#lt #item_ty as #krate::IntoItems<#for_type>>::IntoIter };
+                let map_fn = quote! { fn(&#lt #item_ty) -> #associated_iter };
                 Self {
-                    concrete: quote! { &'a [#item_ty] },
+                    concrete: quote! { &#lt [#item_ty] },
                     associated_iter,
-                    associated_rows: quote! { ::std::iter::Map<::std::slice::Iter<'a, #item_ty>, #map_fn> },
-                    body: quote! { self.iter().map(<&'a #item_ty as itemize::IntoItems<#for_type>>::into_items) },
-                    generics: generics.with_lifetimes([quote! { 'a }]).to_token_stream(),
-                    constraints: quote! { &'a #item_ty: itemize::IntoItems<#for_type> },
+                    associated_rows: quote! { ::core::iter::Map<::core::slice::Iter<#lt, #item_ty>, #map_fn> },
+                    body: quote! { ::core::iter::Iterator::map(self.iter(), <&#lt #item_ty as #krate::IntoItems<#for_type>>::into_items) },
+                    generics: generics.with_lifetimes([quote! { #lt }]).to_token_stream(),
+                    constraints: quote! { &#lt #item_ty: #krate::IntoItems<#for_type> },
                 }
             }
             CollectionType::Array => {
                 let associated_iter =
-                    quote! { <#item_ty as itemize::IntoItems<#for_type>>::IntoIter };
+                    quote! { <#item_ty as #krate::IntoItems<#for_type>>::IntoIter };
                 let map_fn = quote! { fn(#item_ty) -> #associated_iter };
                 Self {
                     concrete: quote! { [#item_ty; #const_ty] },
                     associated_iter,
-                    associated_rows: quote! { ::std::iter::Map<::std::array::IntoIter<#item_ty, #const_ty>, #map_fn> },
-                    body: quote! { self.into_iter().map(<#item_ty as itemize::IntoItems<#for_type>>::into_items) },
+                    associated_rows: quote! { ::core::iter::Map<::core::array::IntoIter<#item_ty, #const_ty>, #map_fn> },
+                    body: quote! { ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(self), <#item_ty as #krate::IntoItems<#for_type>>::into_items) },
                     generics: generics
-                        .with_consts([quote! { const #const_ty: usize }])
+                        .with_consts([quote! { const #const_ty: ::core::primitive::usize }])
                         .to_token_stream(),
-                    constraints: quote! { #item_ty: itemize::IntoItems<#for_type> },
+                    constraints: quote! { #item_ty: #krate::IntoItems<#for_type> },
                 }
             }
         }
     }
 
     fn from_tuple(ctx: &Context<'_>, len: usize) -> Self {
+        let krate = &ctx.krate;
         let for_type: &TokenStream = &ctx.concrete;
 
-        let target = (0..len).map(tuple_type_ident).collect::<Vec<_>>();
+        let target = (0..len).map(|i| ctx.names.tuple(i)).collect::<Vec<_>>();
 
         let generics = ctx.generics().with_types(&target).to_token_stream();
 
         let constraints = target
             .iter()
-            .map(|target| quote! { #target: itemize::IntoItems<#for_type> });
-
-        let iter_types: Vec<TokenStream> = target
-            .iter()
-            .map(|t| quote! { <#t as itemize::IntoItems<#for_type>>::IntoIter })
-            .collect();
-
-        let body = tuple_rows_impl(len, |name| quote! { #name.into_items() });
+            .map(|target| quote! { #target: #krate::IntoItems<#for_type> });
+
+        let (associated_iter, body) = if shared_tuple(len) {
+            (
+                quote! { <Self as #krate::__private::TupleRows<#for_type>>::RowIter },
+                quote! { #krate::__private::TupleRows::<#for_type>::tuple_rows(self) },
+            )
+        } else {
+            let iter_types: Vec<TokenStream> = target
+                .iter()
+                .map(|t| quote! { <#t as #krate::IntoItems<#for_type>>::IntoIter })
+                .collect();
+            (
+                tuple_rows_associated(krate, len, &iter_types, for_type),
+                tuple_rows_impl(
+                    krate,
+                    len,
+                    |name| quote! { #krate::IntoItems::into_items(#name) },
+                ),
+            )
+        };
 
         Self {
             concrete: quote! { (#(#target,)*) },
-            associated_iter: tuple_rows_associated(len, &iter_types, for_type),
-            associated_rows: quote! { ::std::array::IntoIter<Self::RowIter, #len> },
+            associated_iter,
+            associated_rows: quote! { ::core::array::IntoIter<Self::RowIter, #len> },
             body,
             generics,
             constraints: quote! { #(#constraints,)* },
         }
     }
 
+    /// Points diagnostics about unsatisfied constraints at the option's `span`.
+    fn spanned(mut self, span: Span) -> Self {
+        self.constraints = respan(&self.constraints, span);
+        self
+    }
+
     fn generate(self, ctx: &Context<'_>) -> TokenStream {
+        let krate = &ctx.krate;
         let row_iter = self.associated_iter;
         let rows = self.associated_rows;
         let into_impl = self.body;
@@ -124,7 +161,7 @@ impl Config {
         let for_type = &ctx.concrete;
 
         quote! {
-            impl #generics itemize::IntoRows<#for_type> for #target_ty
+            impl #generics #krate::IntoRows<#for_type> for #target_ty
             where
                 #(#predicates,)*
                 #conv_from
@@ -1,4 +1,91 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+use crate::{
+    context::{self, Context, TraitKind},
+    util::{GenericList, respan},
+};
+
 pub mod into_items;
 pub mod into_rows;
 pub mod try_into_items;
 pub mod try_into_rows;
+
+/// Marker impl opting the target into the tuple impls of `itemize::__private`, emitted
+/// for `tuples(lean)` in place of one impl per tuple size.
+fn lean_tuples(ctx: &Context<'_>, marker: TokenStream, generics: GenericList) -> TokenStream {
+    let krate = &ctx.krate;
+    let item = &ctx.concrete;
+    let predicates = ctx.where_predicates.iter().flatten();
+
+    quote! {
+        impl #generics #krate::__private::#marker for #item
+        where
+            #(#predicates,)*
+        {}
+    }
+}
+
+/// Evidence that the derive of `kind` is present on `ast`, and checks that the options
+/// it ignores are read by another derive of the target, reported at each option.
+/// Emitted only while some option read by a subset of the traits is set.
+pub(crate) fn option_checks(
+    ast: &DeriveInput,
+    kind: TraitKind,
+    krate: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let uses = context::option_uses(ast)?;
+    if uses.is_empty() {
+        return Ok(TokenStream::new());
+    }
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let evidence = kind.evidence();
+
+    let checks = uses
+        .iter()
+        .filter(|option| !option.readers.contains(&kind))
+        .map(|option| {
+            let check = format_ident!("{}_used", option.name);
+            let mut readers = option
+                .readers
+                .iter()
+                .map(|reader| {
+                    let evidence = reader.evidence();
+                    quote! { <#ident #ty_generics>::#evidence }
+                })
+                .collect::<Vec<_>>();
+            if readers.len() == 1 {
+                readers.push(quote! { #krate::__private::NotDerived });
+            }
+            // Every token at the option, so that the same check emitted by several
+            // derives reports once.
+            respan(
+                &quote! { #krate::__private::#check((#(#readers),*)); },
+                option.span,
+            )
+        })
+        .collect::<Vec<_>>();
+    let checks = (!checks.is_empty()).then(|| {
+        quote! {
+            const _: () = {
+                #[allow(unused_imports)]
+                use #krate::__private::Evidence as _;
+                #[allow(dead_code)]
+                fn __itemize_check #impl_generics () #where_clause {
+                    #(#checks)*
+                }
+            };
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            const #evidence: #krate::__private::Derived = #krate::__private::Derived;
+        }
+        #checks
+    })
+}
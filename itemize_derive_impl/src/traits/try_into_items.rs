@@ -1,31 +1,134 @@
-use proc_macro2::TokenStream;
-use quote::{ToTokens, quote};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::spanned::Spanned;
 
 use crate::{
-    context::{CollectionType, Context},
-    util::{const_ident, item_ident, tuple_items_impl, tuple_type_ident},
+    context::{BorrowMode, CollectionType, Context, SourceType, Tuples},
+    util::{respan, tuple_items_body},
 };
 
 pub(crate) fn generate(ctx: &Context<'_>) -> TokenStream {
-    let mut configs = vec![];
+    let mut configs = Vec::new();
 
-    for collection_type in &ctx.attributes.collections {
-        configs.push(Config::from_collection(ctx, collection_type).generate(ctx))
+    for collection_type in ctx.attributes.collections.keys() {
+        configs.push(
+            Config::from_collection(ctx, collection_type)
+                .spanned(ctx.attributes.collection_span(collection_type))
+                .generate(ctx),
+        )
     }
 
-    for type_ in &ctx.attributes.types {
-        configs.push(Config::from_type(ctx, type_).generate(ctx))
+    for source in &ctx.attributes.types {
+        configs.push(Config::from_type(ctx, source).generate(ctx))
     }
 
-    if let Some(tuples) = ctx.attributes.tuples {
-        for len in tuples.iter() {
-            configs.push(Config::from_tuple(ctx, len).generate(ctx))
+    for type_ in &ctx.attributes.parse {
+        configs.push(Config::from_parsed(ctx, type_).generate(ctx))
+    }
+
+    // Elements going through single-value impls may also be the target itself
+    if ctx.try_single_elements() {
+        configs.push(Config::from_target(ctx).generate(ctx))
+    }
+
+    match ctx.attributes.tuples {
+        Some(Tuples::Range(range)) => {
+            for len in range.iter() {
+                configs.push(
+                    Config::from_tuple(ctx, len)
+                        .spanned(ctx.attributes.tuples_span())
+                        .generate(ctx),
+                )
+            }
+        }
+        Some(Tuples::Lean) => configs.push(super::lean_tuples(
+            ctx,
+            quote! { LeanTryItems },
+            ctx.generics(),
+        )),
+        None => {}
+    }
+
+    if let Some(mode) = ctx.attributes.borrow {
+        if ctx
+            .attributes
+            .collections
+            .contains_key(&CollectionType::Slice)
+        {
+            configs.push(Config::from_borrowed_vec(ctx, mode).generate(ctx))
+        }
+        for source in ctx.attributes.owned_types() {
+            configs.push(Config::from_borrowed_type(ctx, source, mode).generate(ctx))
         }
     }
 
     quote! { #(#configs)* }
 }
 
+/// Expression converting `value` of `source` into a `Result` of the target and the
+/// bounds it needs. A wrapped source is converted into the field inline, since a
+/// `TryFrom` impl on the target would conflict with the `From` impl of `IntoItems`.
+fn conversion(
+    ctx: &Context<'_>,
+    source: &SourceType,
+    value: TokenStream,
+    error_ty: &TokenStream,
+) -> (TokenStream, TokenStream) {
+    let item = &ctx.concrete;
+    match ctx.wrapper(source) {
+        Some(wrapper) => {
+            let field_ty = ctx.resolve(&wrapper.field.ty);
+            let value = source.try_conversion_expr(&field_ty, value);
+            let construct = wrapper.construct(quote! { value });
+            (
+                quote! { ::core::result::Result::map(#value, |value| #construct) },
+                source.try_conversion_bounds(&field_ty, error_ty),
+            )
+        }
+        None => (
+            source.try_conversion_expr(item, value),
+            source.try_conversion_bounds(item, error_ty),
+        ),
+    }
+}
+
+/// Bound converting an element `ty` of a tuple or collection through its own
+/// single-value impl.
+fn single_bound(ctx: &Context<'_>, ty: &TokenStream, error_ty: &TokenStream) -> TokenStream {
+    let krate = &ctx.krate;
+    let item = &ctx.concrete;
+    quote! { #ty: #krate::TryIntoItems<#item, #error_ty, IntoIter = ::core::iter::Once<::core::result::Result<#item, #error_ty>>> }
+}
+
+/// Bound converting an element `ty` of a tuple or collection through the target's
+/// `TryFrom`.
+fn try_from_bound(ctx: &Context<'_>, ty: &TokenStream, error_ty: &TokenStream) -> TokenStream {
+    let item = &ctx.concrete;
+    quote! { #item: ::core::convert::TryFrom<#ty>, <#item as ::core::convert::TryFrom<#ty>>::Error: ::core::convert::Into<#error_ty> }
+}
+
+/// Bound on and function converting an element `ty` of a collection, through its own
+/// single-value impl when some sources are only converted there.
+fn element(
+    ctx: &Context<'_>,
+    ty: TokenStream,
+    error_ty: &TokenStream,
+) -> (TokenStream, TokenStream) {
+    let krate = &ctx.krate;
+    let item = &ctx.concrete;
+    if ctx.try_sourced() {
+        (
+            single_bound(ctx, &ty, error_ty),
+            quote! { #krate::__private::try_single::<#item, #ty, #error_ty> },
+        )
+    } else {
+        (
+            try_from_bound(ctx, &ty, error_ty),
+            quote! { #krate::__private::try_from::<#item, #ty, #error_ty> },
+        )
+    }
+}
+
 struct Config {
     concrete: TokenStream,
     associated: TokenStream,
@@ -36,30 +139,77 @@ struct Config {
 }
 
 impl Config {
-    fn from_type(ctx: &Context<'_>, type_: &syn::Type) -> Self {
+    fn from_type(ctx: &Context<'_>, source: &SourceType) -> Self {
         let error_ty = ctx.error_ty();
         let item: &TokenStream = &ctx.concrete;
+        let type_ = &source.ty;
         let generics = ctx
             .error_generics()
-            .with_lifetimes_from_type(type_)
+            .with_generics_from_source(source)
             .to_token_stream();
+        let (value, constraints) = conversion(ctx, source, quote! { self }, &error_ty);
 
         Self {
-            associated: quote! { ::std::iter::Once<Result<#item, #error_ty>> },
-            body: quote! { ::std::iter::once(<#item as ::std::convert::TryFrom<#type_>>::try_from(self).map_err(Into::into)) },
+            associated: quote! { ::core::iter::Once<::core::result::Result<#item, #error_ty>> },
+            body: quote! { ::core::iter::once(#value) },
             concrete: quote! { #type_ },
             generics,
-            constraints: quote! { #item: ::std::convert::TryFrom<#type_>, <#item as ::std::convert::TryFrom<#type_>>::Error: Into<#error_ty> },
+            constraints,
+            error_ty,
+        }
+    }
+
+    /// A `parse(...)` source, converted through the target's `FromStr`.
+    fn from_parsed(ctx: &Context<'_>, type_: &syn::Type) -> Self {
+        let krate = &ctx.krate;
+        let error_ty = ctx.error_ty();
+        let item: &TokenStream = &ctx.concrete;
+        let generics = ctx
+            .error_generics()
+            .with_generics_from_source(&SourceType::from(type_.clone()))
+            .to_token_stream();
+        let span = type_.span();
+
+        Self {
+            associated: quote! { ::core::iter::Once<::core::result::Result<#item, #error_ty>> },
+            body: quote! { ::core::iter::once(#krate::__private::parse::<#item, #type_, #error_ty>(self)) },
+            concrete: quote! { #type_ },
+            generics,
+            constraints: quote_spanned! {span=>
+                #item: ::core::str::FromStr,
+                <#item as ::core::str::FromStr>::Err: ::core::convert::Into<#error_ty>
+            },
+            error_ty,
+        }
+    }
+
+    /// The target itself as its only item, which never fails.
+    fn from_target(ctx: &Context<'_>) -> Self {
+        let error_ty = ctx.error_ty();
+        let item = &ctx.concrete;
+
+        Self {
+            associated: quote! { ::core::iter::Once<::core::result::Result<#item, #error_ty>> },
+            body: quote! { ::core::iter::once(::core::result::Result::Ok(self)) },
+            concrete: quote! { #item },
+            generics: ctx.error_generics().to_token_stream(),
+            constraints: TokenStream::new(),
             error_ty,
         }
     }
 
     fn from_collection(ctx: &Context<'_>, collection_type: &CollectionType) -> Self {
-        let item_ty = item_ident();
-        let const_ty = const_ident();
+        let lt = &ctx.names.lifetime;
+        let item_ty = &ctx.names.item;
+        let const_ty = &ctx.names.konst;
         let for_type = &ctx.concrete;
 
-        let map_item = map_item_try_from();
+        if let (CollectionType::Slice, Some(mode)) = (collection_type, ctx.attributes.borrow) {
+            return Self {
+                concrete: quote! { &#lt [#item_ty] },
+                ..Self::from_borrowed_vec(ctx, mode)
+            };
+        }
 
         match collection_type {
             CollectionType::Vec => {
@@ -68,12 +218,13 @@ impl Config {
                     .with_types([&item_ty])
                     .to_token_stream();
                 let error_ty = ctx.error_ty();
+                let (constraints, convert) = element(ctx, quote! { #item_ty }, &error_ty);
                 Self {
-                    associated: quote! { ::std::iter::Map<::std::vec::IntoIter<#item_ty>, fn(#item_ty) -> Result<#for_type, #error_ty>> },
-                    body: quote! { #map_item self.into_iter().map(map_item::<#for_type, #item_ty, #error_ty>) },
-                    concrete: quote! { Vec<#item_ty> },
+                    associated: quote! { ::core::iter::Map<::std::vec::IntoIter<#item_ty>, fn(#item_ty) -> ::core::result::Result<#for_type, #error_ty>> },
+                    body: quote! { ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(self), #convert) },
+                    concrete: quote! { ::std::vec::Vec<#item_ty> },
                     generics,
-                    constraints: quote! { #for_type: ::std::convert::TryFrom<#item_ty>, <#for_type as ::std::convert::TryFrom<#item_ty>>::Error: Into<#error_ty> },
+                    constraints,
                     error_ty,
                 }
             }
@@ -81,15 +232,16 @@ impl Config {
                 let generics = ctx
                     .error_generics()
                     .with_types([&item_ty])
-                    .with_lifetimes([quote! { 'a }])
+                    .with_lifetimes([quote! { #lt }])
                     .to_token_stream();
                 let error_ty = ctx.error_ty();
+                let (constraints, convert) = element(ctx, quote! { &#lt #item_ty }, &error_ty);
                 Self {
-                    associated: quote! { ::std::iter::Map<::std::slice::Iter<'a, #item_ty>, fn(&'a #item_ty) -> Result<#for_type, #error_ty>> },
-                    body: quote! { #map_item self.iter().map(map_item::<#for_type, &'a #item_ty, #error_ty>) },
-                    concrete: quote! { &'a [#item_ty] },
+                    associated: quote! { ::core::iter::Map<::core::slice::Iter<#lt, #item_ty>, fn(&#lt #item_ty) -> ::core::result::Result<#for_type, #error_ty>> },
+                    body: quote! { ::core::iter::Iterator::map(self.iter(), #convert) },
+                    concrete: quote! { &#lt [#item_ty] },
                     generics,
-                    constraints: quote! { #for_type: ::std::convert::TryFrom<&'a #item_ty>, <#for_type as ::std::convert::TryFrom<&'a #item_ty>>::Error: Into<#error_ty> },
+                    constraints,
                     error_ty,
                 }
             }
@@ -97,42 +249,103 @@ impl Config {
                 let generics = ctx
                     .error_generics()
                     .with_types([&item_ty])
-                    .with_consts([quote! { const #const_ty: usize }])
+                    .with_consts([quote! { const #const_ty: ::core::primitive::usize }])
                     .to_token_stream();
                 let error_ty = ctx.error_ty();
+                let (constraints, convert) = element(ctx, quote! { #item_ty }, &error_ty);
                 Self {
-                    associated: quote! { ::std::iter::Map<::std::array::IntoIter<#item_ty, #const_ty>, fn(#item_ty) -> Result<#for_type, #error_ty>> },
-                    body: quote! { #map_item self.into_iter().map(map_item::<#for_type, #item_ty, #error_ty>) },
+                    associated: quote! { ::core::iter::Map<::core::array::IntoIter<#item_ty, #const_ty>, fn(#item_ty) -> ::core::result::Result<#for_type, #error_ty>> },
+                    body: quote! { ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(self), #convert) },
                     concrete: quote! { [#item_ty; #const_ty] },
                     generics,
-                    constraints: quote! { #for_type: ::std::convert::TryFrom<#item_ty>, <#for_type as ::std::convert::TryFrom<#item_ty>>::Error: Into<#error_ty> },
+                    constraints,
                     error_ty,
                 }
             }
         }
     }
 
+    /// `&'a Vec<T>` (and `&'a [T]`) turning each borrowed element into an owned `T`.
+    fn from_borrowed_vec(ctx: &Context<'_>, mode: BorrowMode) -> Self {
+        let lt = &ctx.names.lifetime;
+        let item_ty = &ctx.names.item;
+        let for_type = &ctx.concrete;
+        let error_ty = ctx.error_ty();
+        let (adapter, method, bound) = (mode.adapter(), mode.method(), mode.bound());
+        let (constraints, convert) = element(ctx, quote! { #item_ty }, &error_ty);
+
+        Self {
+            associated: quote! { ::core::iter::Map<#adapter<::core::slice::Iter<#lt, #item_ty>>, fn(#item_ty) -> ::core::result::Result<#for_type, #error_ty>> },
+            body: quote! { ::core::iter::Iterator::map(::core::iter::Iterator::#method(self.iter()), #convert) },
+            concrete: quote! { &#lt ::std::vec::Vec<#item_ty> },
+            generics: ctx
+                .error_generics()
+                .with_types([&item_ty])
+                .with_lifetimes([quote! { #lt }])
+                .to_token_stream(),
+            constraints: quote! { #item_ty: #bound, #constraints },
+            error_ty,
+        }
+    }
+
+    /// `&'a Source` for a declared owned source type, going through `TryFrom<Source>`.
+    fn from_borrowed_type(ctx: &Context<'_>, source: &SourceType, mode: BorrowMode) -> Self {
+        let lt = &ctx.names.lifetime;
+        let error_ty = ctx.error_ty();
+        let item: &TokenStream = &ctx.concrete;
+        let type_ = &source.ty;
+        let borrowed: syn::Type = syn::parse_quote! { &#lt #type_ };
+        let generics = ctx
+            .error_generics()
+            .with_generics_from_source(source)
+            .with_lifetimes_from_type(&borrowed)
+            .to_token_stream();
+        let bound = mode.bound();
+        let (value, constraints) =
+            conversion(ctx, source, mode.to_owned(quote! { self }), &error_ty);
+
+        Self {
+            associated: quote! { ::core::iter::Once<::core::result::Result<#item, #error_ty>> },
+            body: quote! { ::core::iter::once(#value) },
+            concrete: quote! { #borrowed },
+            generics,
+            constraints: quote! { #type_: #bound, #constraints },
+            error_ty,
+        }
+    }
+
     fn from_tuple(ctx: &Context<'_>, len: usize) -> Self {
+        let krate = &ctx.krate;
         let for_type: &TokenStream = &ctx.concrete;
 
-        let target = (0..len).map(tuple_type_ident).collect::<Vec<_>>();
+        let target = (0..len).map(|i| ctx.names.tuple(i)).collect::<Vec<_>>();
 
         let generics = ctx.error_generics().with_types(&target).to_token_stream();
 
         let error_ty = ctx.error_ty();
-        let constraint = target
-            .iter()
-            .map(|target| quote! { #for_type: ::std::convert::TryFrom<#target>, <#for_type as ::std::convert::TryFrom<#target>>::Error: Into<#error_ty> });
 
-        let body = tuple_items_impl(
-            len,
-            |name| quote! { <#for_type>::try_from(#name).map_err(Into::into) },
-            |block| quote! { [#block].into_iter() },
-        );
+        // In borrow mode each element is a single declared source or a reference to one,
+        // so elements go through their own single-value impls.
+        let elements = target.iter().map(|target| quote! { #target });
+        let (constraint, convert): (Vec<_>, _) = if ctx.try_single_elements() {
+            (
+                elements
+                    .map(|ty| single_bound(ctx, &ty, &error_ty))
+                    .collect(),
+                quote! { #krate::__private::ViaTryItems<#for_type, #error_ty> },
+            )
+        } else {
+            (
+                elements
+                    .map(|ty| try_from_bound(ctx, &ty, &error_ty))
+                    .collect(),
+                quote! { #krate::__private::ViaTryFrom<#for_type, #error_ty> },
+            )
+        };
 
         Self {
-            associated: quote! { ::std::array::IntoIter<Result<#for_type, #error_ty>, #len> },
-            body,
+            associated: quote! { ::core::array::IntoIter<::core::result::Result<#for_type, #error_ty>, #len> },
+            body: tuple_items_body(krate, len, convert),
             concrete: quote! { (#(#target,)*) },
             generics,
             constraints: quote! { #(#constraint,)* },
@@ -140,7 +353,14 @@ impl Config {
         }
     }
 
+    /// Points diagnostics about unsatisfied constraints at the option's `span`.
+    fn spanned(mut self, span: Span) -> Self {
+        self.constraints = respan(&self.constraints, span);
+        self
+    }
+
     fn generate(self, ctx: &Context<'_>) -> TokenStream {
+        let krate = &ctx.krate;
         let associated = self.associated;
         let body = self.body;
         let generics = self.generics;
@@ -152,7 +372,7 @@ impl Config {
         let item = &ctx.concrete;
 
         quote! {
-            impl #generics itemize::TryIntoItems<#item, #error_ty> for #concrete
+            impl #generics #krate::TryIntoItems<#item, #error_ty> for #concrete
             where
                 #(#predicates,)*
                 #constraints
@@ -166,16 +386,3 @@ impl Config {
         }
     }
 }
-
-fn map_item_try_from() -> TokenStream {
-    quote! {
-        #[inline]
-        fn map_item<Target, Item, E>(item: Item) -> Result<Target, E>
-        where
-            Target: ::std::convert::TryFrom<Item>,
-            Target::Error: Into<E>,
-        {
-            Target::try_from(item).map_err(Into::into)
-        }
-    }
-}
@@ -1,22 +1,38 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 
 use crate::{
-    context::{CollectionType, Context},
-    util::{const_ident, item_ident, tuple_rows_associated, tuple_rows_impl, tuple_type_ident},
+    context::{CollectionType, Context, Tuples},
+    util::{respan, shared_tuple, tuple_rows_associated, tuple_rows_impl},
 };
 
 pub(crate) fn generate(ctx: &Context<'_>) -> TokenStream {
     let mut configs = vec![];
 
-    for collection_type in &ctx.attributes.collections {
-        configs.push(Config::from_collection(ctx, collection_type).generate(ctx))
+    for collection_type in ctx.attributes.collections.keys() {
+        configs.push(
+            Config::from_collection(ctx, collection_type)
+                .spanned(ctx.attributes.collection_span(collection_type))
+                .generate(ctx),
+        )
     }
 
-    if let Some(tuples) = ctx.attributes.tuples {
-        for len in tuples.iter() {
-            configs.push(Config::from_tuple(ctx, len).generate(ctx))
+    match ctx.attributes.tuples {
+        Some(Tuples::Range(range)) => {
+            for len in range.iter() {
+                configs.push(
+                    Config::from_tuple(ctx, len)
+                        .spanned(ctx.attributes.tuples_span())
+                        .generate(ctx),
+                )
+            }
         }
+        Some(Tuples::Lean) => configs.push(super::lean_tuples(
+            ctx,
+            quote! { LeanTryRows },
+            ctx.generics(),
+        )),
+        None => {}
     }
 
     quote! { #(#configs)* }
@@ -34,8 +50,10 @@ struct Config {
 
 impl Config {
     fn from_collection(ctx: &Context<'_>, collection_type: &CollectionType) -> Self {
-        let item_ty = item_ident();
-        let const_ty = const_ident();
+        let lt = &ctx.names.lifetime;
+        let krate = &ctx.krate;
+        let item_ty = &ctx.names.item;
+        let const_ty = &ctx.names.konst;
         let for_type = &ctx.concrete;
 
         match collection_type {
@@ -46,15 +64,15 @@ impl Config {
                     .to_token_stream();
                 let error_ty = ctx.error_ty();
                 let associated_iter =
-                    quote! { <#item_ty as itemize::TryIntoItems<#for_type, #error_ty>>::IntoIter };
+                    quote! { <#item_ty as #krate::TryIntoItems<#for_type, #error_ty>>::IntoIter };
                 let map_fn = quote! { fn(#item_ty) -> #associated_iter };
                 Self {
-                    concrete: quote! { Vec<#item_ty> },
+                    concrete: quote! { ::std::vec::Vec<#item_ty> },
                     associated_iter,
-                    associated_rows: quote! { ::std::iter::Map<::std::vec::IntoIter<#item_ty>, #map_fn> },
-                    body: quote! { self.into_iter().map(<#item_ty as itemize::TryIntoItems<#for_type, #error_ty>>::try_into_items) },
+                    associated_rows: quote! { ::core::iter::Map<::std::vec::IntoIter<#item_ty>, #map_fn> },
+                    body: quote! { ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(self), <#item_ty as #krate::TryIntoItems<#for_type, #error_ty>>::try_into_items) },
                     generics,
-                    constraints: quote! { #item_ty: itemize::TryIntoItems<#for_type, #error_ty> },
+                    constraints: quote! { #item_ty: #krate::TryIntoItems<#for_type, #error_ty> },
                     error_ty,
                 }
             }
@@ -62,18 +80,18 @@ impl Config {
                 let generics = ctx
                     .error_generics()
                     .with_types([&item_ty])
-                    .with_lifetimes([quote! { 'a }])
+                    .with_lifetimes([quote! { #lt }])
                     .to_token_stream();
                 let error_ty = ctx.error_ty();
-                let associated_iter = quote! { <&'a #item_ty as itemize::TryIntoItems<#for_type, #error_ty>>::IntoIter };
-                let map_fn = quote! { fn(&'a #item_ty) -> #associated_iter };
+                let associated_iter = quote! { <&#lt #item_ty as #krate::TryIntoItems<#for_type, #error_ty>>::IntoIter };
+                let map_fn = quote! { fn(&#lt #item_ty) -> #associated_iter };
                 Self {
-                    concrete: quote! { &'a [#item_ty] },
+                    concrete: quote! { &#lt [#item_ty] },
                     associated_iter,
-                    associated_rows: quote! { ::std::iter::Map<::std::slice::Iter<'a, #item_ty>, #map_fn> },
-                    body: quote! { self.iter().map(<&'a #item_ty as itemize::TryIntoItems<#for_type, #error_ty>>::try_into_items) },
+                    associated_rows: quote! { ::core::iter::Map<::core::slice::Iter<#lt, #item_ty>, #map_fn> },
+                    body: quote! { ::core::iter::Iterator::map(self.iter(), <&#lt #item_ty as #krate::TryIntoItems<#for_type, #error_ty>>::try_into_items) },
                     generics,
-                    constraints: quote! { &'a #item_ty: itemize::TryIntoItems<#for_type, #error_ty> },
+                    constraints: quote! { &#lt #item_ty: #krate::TryIntoItems<#for_type, #error_ty> },
                     error_ty,
                 }
             }
@@ -81,19 +99,19 @@ impl Config {
                 let generics = ctx
                     .error_generics()
                     .with_types([&item_ty])
-                    .with_consts([quote! { const #const_ty: usize }])
+                    .with_consts([quote! { const #const_ty: ::core::primitive::usize }])
                     .to_token_stream();
                 let error_ty = ctx.error_ty();
                 let associated_iter =
-                    quote! { <#item_ty as itemize::TryIntoItems<#for_type, #error_ty>>::IntoIter };
+                    quote! { <#item_ty as #krate::TryIntoItems<#for_type, #error_ty>>::IntoIter };
                 let map_fn = quote! { fn(#item_ty) -> #associated_iter };
                 Self {
                     concrete: quote! { [#item_ty; #const_ty] },
                     associated_iter,
-                    associated_rows: quote! { ::std::iter::Map<::std::array::IntoIter<#item_ty, #const_ty>, #map_fn> },
-                    body: quote! { self.into_iter().map(<#item_ty as itemize::TryIntoItems<#for_type, #error_ty>>::try_into_items) },
+                    associated_rows: quote! { ::core::iter::Map<::core::array::IntoIter<#item_ty, #const_ty>, #map_fn> },
+                    body: quote! { ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(self), <#item_ty as #krate::TryIntoItems<#for_type, #error_ty>>::try_into_items) },
                     generics,
-                    constraints: quote! { #item_ty: itemize::TryIntoItems<#for_type, #error_ty> },
+                    constraints: quote! { #item_ty: #krate::TryIntoItems<#for_type, #error_ty> },
                     error_ty,
                 }
             }
@@ -101,28 +119,42 @@ impl Config {
     }
 
     fn from_tuple(ctx: &Context<'_>, len: usize) -> Self {
+        let krate = &ctx.krate;
         let for_type: &TokenStream = &ctx.concrete;
 
-        let target = (0..len).map(tuple_type_ident).collect::<Vec<_>>();
+        let target = (0..len).map(|i| ctx.names.tuple(i)).collect::<Vec<_>>();
 
         let generics = ctx.error_generics().with_types(&target).to_token_stream();
 
         let error_ty = ctx.error_ty();
         let constraints = target
             .iter()
-            .map(|target| quote! { #target: itemize::TryIntoItems<#for_type, #error_ty> });
-
-        let iter_types: Vec<TokenStream> = target
-            .iter()
-            .map(|t| quote! { <#t as itemize::TryIntoItems<#for_type, #error_ty>>::IntoIter })
-            .collect();
-
-        let body = tuple_rows_impl(len, |name| quote! { #name.try_into_items() });
+            .map(|target| quote! { #target: #krate::TryIntoItems<#for_type, #error_ty> });
+
+        let (associated_iter, body) = if shared_tuple(len) {
+            (
+                quote! { <Self as #krate::__private::TryTupleRows<#for_type, #error_ty>>::RowIter },
+                quote! { #krate::__private::TryTupleRows::<#for_type, #error_ty>::try_tuple_rows(self) },
+            )
+        } else {
+            let iter_types: Vec<TokenStream> = target
+                .iter()
+                .map(|t| quote! { <#t as #krate::TryIntoItems<#for_type, #error_ty>>::IntoIter })
+                .collect();
+            (
+                tuple_rows_associated(krate, len, &iter_types, for_type),
+                tuple_rows_impl(
+                    krate,
+                    len,
+                    |name| quote! { #krate::TryIntoItems::try_into_items(#name) },
+                ),
+            )
+        };
 
         Self {
             concrete: quote! { (#(#target,)*) },
-            associated_iter: tuple_rows_associated(len, &iter_types, for_type),
-            associated_rows: quote! { ::std::array::IntoIter<Self::RowIter, #len> },
+            associated_iter,
+            associated_rows: quote! { ::core::array::IntoIter<Self::RowIter, #len> },
             body,
             generics,
             constraints: quote! { #(#constraints,)* },
@@ -130,7 +162,14 @@ impl Config {
         }
     }
 
+    /// Points diagnostics about unsatisfied constraints at the option's `span`.
+    fn spanned(mut self, span: Span) -> Self {
+        self.constraints = respan(&self.constraints, span);
+        self
+    }
+
     fn generate(self, ctx: &Context<'_>) -> TokenStream {
+        let krate = &ctx.krate;
         let associated_iter = self.associated_iter;
         let associated_rows = self.associated_rows;
         let body = self.body;
@@ -143,7 +182,7 @@ impl Config {
         let for_type = &ctx.concrete;
 
         quote! {
-            impl #generics itemize::TryIntoRows<#for_type, #error_ty> for #concrete
+            impl #generics #krate::TryIntoRows<#for_type, #error_ty> for #concrete
             where
                 #(#predicates,)*
                 #constraints
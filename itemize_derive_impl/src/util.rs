@@ -1,94 +1,155 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
 use quote::{ToTokens, format_ident, quote};
 use syn::{Generics, Ident, Lifetime};
 
-pub(crate) fn tuple_type_ident(len: usize) -> Ident {
-    format_ident!("__A{}", len)
+use crate::context::SourceType;
+
+/// Names of the generic parameters injected into generated impls, chosen so they
+/// don't collide with the target's own parameters or with names used in its attributes.
+#[derive(Clone)]
+pub(crate) struct InjectedNames {
+    pub(crate) item: Ident,
+    pub(crate) konst: Ident,
+    pub(crate) error: Ident,
+    pub(crate) lifetime: Lifetime,
+    tuple_prefix: String,
 }
 
-pub(crate) fn item_ident() -> Ident {
-    format_ident!("__T")
+impl InjectedNames {
+    pub(crate) fn new(taken: &HashSet<String>) -> Self {
+        let fresh = |base: &str| {
+            std::iter::once(base.to_string())
+                .chain((1..).map(|n| format!("{base}{n}")))
+                .find(|name| !taken.contains(name))
+                .unwrap()
+        };
+        // Tuple parameters are numbered, so the prefix must not start any taken name.
+        let tuple_prefix = std::iter::once("__A".to_string())
+            .chain((1..).map(|n| format!("__A{n}_")))
+            .find(|prefix| {
+                !taken.iter().any(|name| {
+                    name.strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.chars().all(|c| c.is_ascii_digit()))
+                })
+            })
+            .unwrap();
+
+        Self {
+            item: format_ident!("{}", fresh("__T")),
+            konst: format_ident!("{}", fresh("__N")),
+            error: format_ident!("{}", fresh("E")),
+            lifetime: Lifetime::new(&fresh("'a"), proc_macro2::Span::call_site()),
+            tuple_prefix,
+        }
+    }
+
+    pub(crate) fn tuple(&self, idx: usize) -> Ident {
+        format_ident!("{}{}", self.tuple_prefix, idx)
+    }
+}
+
+/// Collects every identifier and lifetime name appearing in `tokens`.
+pub(crate) fn collect_names(tokens: TokenStream, names: &mut HashSet<String>) {
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        match token {
+            proc_macro2::TokenTree::Ident(ident) => {
+                names.insert(ident.to_string());
+            }
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(proc_macro2::TokenTree::Ident(ident)) = tokens.next() {
+                    names.insert(format!("'{ident}"));
+                }
+            }
+            proc_macro2::TokenTree::Group(group) => collect_names(group.stream(), names),
+            _ => {}
+        }
+    }
 }
 
-pub(crate) fn const_ident() -> Ident {
-    format_ident!("__N")
+/// Largest tuple size `itemize::__private` provides the tuple conversions for; bodies
+/// of larger tuples are generated inline.
+const SHARED_TUPLE_LEN: usize = 16;
+
+/// Whether tuples of `len` elements go through the conversions of `itemize::__private`.
+pub(crate) fn shared_tuple(len: usize) -> bool {
+    len <= SHARED_TUPLE_LEN
 }
 
-pub(crate) fn tuple_items_impl(
+/// Body of a tuple impl yielding each element converted through `convert`, one of the
+/// element conversions of `itemize::__private` such as `ViaFrom<Target>`.
+pub(crate) fn tuple_items_body(
+    krate: &TokenStream,
     len: usize,
-    f: impl Fn(Ident) -> TokenStream,
-    wrap: impl Fn(TokenStream) -> TokenStream,
+    convert: TokenStream,
 ) -> TokenStream {
+    if shared_tuple(len) {
+        return quote! { #krate::__private::TupleItems::<#convert>::tuple_items(self) };
+    }
     let names = tuple_names(len);
-    let block = names
-        .iter()
-        .cloned()
-        .map(f)
-        .fold(quote! {}, |acc, x| match acc.is_empty() {
-            true => x,
-            false => quote! { #acc, #x },
-        });
-
-    let body = wrap(block);
     let destructure = tuple_destructure(&names);
     quote! {
         #destructure
-        #body
+        ::core::iter::IntoIterator::into_iter([#(<#convert as #krate::__private::Convert<_>>::convert(#names)),*])
     }
 }
 
 /// Generates body for tuple IntoRows/TryIntoRows with Either wrapping.
-pub(crate) fn tuple_rows_impl(len: usize, f: impl Fn(Ident) -> TokenStream) -> TokenStream {
+pub(crate) fn tuple_rows_impl(
+    krate: &TokenStream,
+    len: usize,
+    f: impl Fn(Ident) -> TokenStream,
+) -> TokenStream {
     let names = tuple_names(len);
     let destructure = tuple_destructure(&names);
 
     let exprs = names.iter().enumerate().map(|(i, name)| {
         let base = f(name.clone());
-        either_val(i, len, base)
+        either_val(krate, i, len, base)
     });
 
     quote! {
-        use itemize::either::Either::*;
         #destructure
-        [#(#exprs),*].into_iter()
+        ::core::iter::IntoIterator::into_iter([#(#exprs),*])
     }
 }
 
 pub(crate) fn tuple_rows_associated(
+    krate: &TokenStream,
     len: usize,
     iter_types: &[TokenStream],
     for_type: impl ToTokens,
 ) -> TokenStream {
     match len {
-        0 => quote! { ::std::iter::Empty<#for_type> },
+        0 => quote! { ::core::iter::Empty<#for_type> },
         1 => iter_types[0].clone(),
-        _ => either_type(iter_types),
+        _ => either_type(krate, iter_types),
     }
 }
 
 /// Builds a nested Either type from a list of types.
-fn either_type(types: &[TokenStream]) -> TokenStream {
+fn either_type(krate: &TokenStream, types: &[TokenStream]) -> TokenStream {
     match types.len() {
         0 => panic!("either_type requires at least one type"),
         1 => types[0].clone(),
         _ => {
             let head = &types[0];
-            let tail = either_type(&types[1..]);
-            quote! { itemize::Either<#head, #tail> }
+            let tail = either_type(krate, &types[1..]);
+            quote! { #krate::Either<#head, #tail> }
         }
     }
 }
 
-fn either_val(idx: usize, len: usize, expr: TokenStream) -> TokenStream {
+fn either_val(krate: &TokenStream, idx: usize, len: usize, expr: TokenStream) -> TokenStream {
     if len == 1 {
         expr
     } else if idx == 0 {
-        quote! { Left(#expr) }
+        quote! { #krate::Either::Left(#expr) }
     } else {
-        let inner = either_val(idx - 1, len - 1, expr);
-        quote! { Right(#inner) }
+        let inner = either_val(krate, idx - 1, len - 1, expr);
+        quote! { #krate::Either::Right(#inner) }
     }
 }
 
@@ -107,6 +168,80 @@ fn tuple_destructure(names: &[Ident]) -> TokenStream {
     }
 }
 
+/// Replaces every identifier in `tokens` found in `substitutions` with its tokens.
+pub(crate) fn substitute(
+    tokens: TokenStream,
+    substitutions: &HashMap<String, TokenStream>,
+) -> TokenStream {
+    if substitutions.is_empty() {
+        return tokens;
+    }
+    tokens
+        .into_iter()
+        .flat_map(|tree| match tree {
+            TokenTree::Ident(ident) => match substitutions.get(&ident.to_string()) {
+                Some(replacement) => replacement.clone(),
+                None => TokenTree::Ident(ident).into_token_stream(),
+            },
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), substitute(group.stream(), substitutions));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced).into_token_stream()
+            }
+            tree => tree.into_token_stream(),
+        })
+        .collect()
+}
+
+/// Re-spans every token of `tokens` so diagnostics about them point at `span`.
+pub(crate) fn respan(tokens: &TokenStream, span: Span) -> TokenStream {
+    tokens
+        .clone()
+        .into_iter()
+        .map(|mut tree| {
+            if let TokenTree::Group(group) = &tree {
+                let mut respanned = Group::new(group.delimiter(), respan(&group.stream(), span));
+                respanned.set_span(span);
+                tree = TokenTree::Group(respanned);
+            }
+            tree.set_span(span);
+            tree
+        })
+        .collect()
+}
+
+/// Whether `ty` is certainly defined outside the invoking crate: a primitive, a path
+/// into `std`, `core` or `alloc`, or a tuple, array or slice, possibly behind
+/// references. Other paths may name local types and are left to the compiler.
+pub(crate) fn foreign_type(ty: &syn::Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32",
+        "i64", "i128", "isize", "f32", "f64",
+    ];
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => {
+            let segments = &path.path.segments;
+            match segments.first() {
+                Some(first) if segments.len() > 1 || path.path.leading_colon.is_some() => {
+                    ["std", "core", "alloc"]
+                        .iter()
+                        .any(|name| first.ident == name)
+                }
+                Some(first) => {
+                    first.arguments.is_none() && PRIMITIVES.iter().any(|name| first.ident == name)
+                }
+                None => false,
+            }
+        }
+        syn::Type::Reference(reference) => foreign_type(&reference.elem),
+        syn::Type::Paren(paren) => foreign_type(&paren.elem),
+        syn::Type::Group(group) => foreign_type(&group.elem),
+        syn::Type::Tuple(_) | syn::Type::Array(_) | syn::Type::Slice(_) => true,
+        _ => false,
+    }
+}
+
 pub(crate) struct GenericList {
     params: Vec<GenericParam>,
 }
@@ -195,6 +330,23 @@ impl GenericList {
         }
         self
     }
+
+    /// Parameters declared by a source with `for<...>`, plus the lifetimes of the
+    /// source and of its `via` type, if any.
+    pub(crate) fn with_generics_from_source(mut self, source: &SourceType) -> Self {
+        for param in &source.generics {
+            self.params.push(match param {
+                syn::GenericParam::Lifetime(p) => GenericParam::Lifetime(p.to_token_stream()),
+                syn::GenericParam::Const(p) => GenericParam::Const(p.to_token_stream()),
+                syn::GenericParam::Type(p) => GenericParam::Type(p.to_token_stream()),
+            });
+        }
+        let list = self.with_lifetimes_from_type(&source.ty);
+        match &source.via {
+            Some(via) => list.with_lifetimes_from_type(via),
+            None => list,
+        }
+    }
 }
 
 impl ToTokens for GenericList {
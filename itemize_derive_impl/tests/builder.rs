@@ -0,0 +1,51 @@
+//! `ImplBuilder` must generate the same impls as the equivalent `impl_items!`.
+
+use itemize_derive_impl::{CollectionType, ImplBuilder, handle_impl_items};
+use quote::quote;
+
+#[test]
+fn matches_impl_items() {
+    let builder = ImplBuilder::new(syn::parse_quote!(UserId), syn::Generics::default())
+        .types([quote!(u64), quote!(&'a str via String)])
+        .tuples(1..=4)
+        .collections([CollectionType::Vec, CollectionType::Slice])
+        .error_type(syn::parse_quote!(Error))
+        .crate_path(syn::parse_quote!(::my_crate::itemize));
+    let expected = handle_impl_items(quote! {
+        IntoItems, TryIntoItems for UserId:
+            types(u64, &'a str via String),
+            tuples(1..=4),
+            collections(vec, slice),
+            error_type(Error),
+            crate = ::my_crate::itemize
+    });
+    let (into_items, try_into_items) = (builder.into_items(), builder.try_into_items());
+    let actual = quote! { #into_items #try_into_items };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn declares_generics_on_every_impl() {
+    let tokens = ImplBuilder::new(syn::parse_quote!(Wrapper<T>), syn::parse_quote!(<T: Clone>))
+        .types([quote!(u64)])
+        .tuples(1..=2)
+        .collections([CollectionType::Vec])
+        .into_rows();
+    let file: syn::File = syn::parse2(tokens).unwrap();
+    assert!(!file.items.is_empty());
+    for item in &file.items {
+        let syn::Item::Impl(item) = item else {
+            panic!("expected only impls");
+        };
+        let first = item.generics.params.first();
+        assert_eq!(quote!(#first).to_string(), "T : Clone");
+    }
+}
+
+#[test]
+fn rejects_invalid_options() {
+    let tokens = ImplBuilder::new(syn::parse_quote!(UserId), syn::Generics::default())
+        .tuples(0..=3)
+        .into_items();
+    assert!(tokens.to_string().contains("compile_error"));
+}
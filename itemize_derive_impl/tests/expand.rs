@@ -0,0 +1,56 @@
+//! Expansion must be identical for the same input, whatever order the options
+//! are written in.
+
+use itemize_derive_impl::{TraitKind, expand};
+use quote::quote;
+
+#[test]
+fn expansion_is_stable() {
+    let input = quote! {
+        #[items_from(types(u32), tuples(2), collections(vec, slice, array))]
+        struct Count(u32);
+    };
+    let first = expand(TraitKind::IntoItems, input.clone());
+    for _ in 0..8 {
+        assert_eq!(expand(TraitKind::IntoItems, input.clone()), first);
+    }
+}
+
+#[test]
+fn collection_order_does_not_matter() {
+    let forward = quote! {
+        #[items_from(types(u32), collections(vec, slice, array), error_type(E))]
+        struct Count(u32);
+    };
+    let reversed = quote! {
+        #[items_from(types(u32), collections(array, slice, vec), error_type(E))]
+        struct Count(u32);
+    };
+    for kind in [
+        TraitKind::IntoItems,
+        TraitKind::TryIntoItems,
+        TraitKind::IntoRows,
+        TraitKind::TryIntoRows,
+    ] {
+        assert_eq!(
+            expand(kind, forward.clone()),
+            expand(kind, reversed.clone())
+        );
+    }
+}
+
+#[test]
+fn expansion_is_pretty_printed() {
+    let output = expand(
+        TraitKind::IntoItems,
+        quote! {
+            #[items_from(types(u32))]
+            struct Count(u32);
+        },
+    );
+    assert!(
+        output.starts_with("impl ::itemize::IntoItems<Count> for u32\n"),
+        "{output}"
+    );
+    assert!(output.lines().count() > 1);
+}
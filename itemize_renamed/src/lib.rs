@@ -0,0 +1,29 @@
+//! Depends on itemize only as `inner`, so `::itemize` does not resolve here and the
+//! generated code must go through `crate = path`.
+
+/// A facade re-exporting the renamed dependency.
+pub mod facade {
+    pub use ::inner;
+}
+
+use facade::inner::{IntoItems, IntoRows, TryIntoItems, accepts};
+
+#[derive(Debug, PartialEq, IntoItems, IntoRows)]
+#[items_from(types(u32), tuples(2), collections(vec), wrap, crate = facade::inner)]
+pub struct Count(pub u32);
+
+#[derive(Debug, PartialEq, TryIntoItems)]
+#[items_from(parse(&'a str), tuples(2), collections(vec), crate = ::inner)]
+pub struct Int(pub i64);
+
+impl core::str::FromStr for Int {
+    type Err = core::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Int)
+    }
+}
+
+#[accepts(crate = facade::inner)]
+pub fn counts(#[items] counts: Vec<Count>) -> Vec<u32> {
+    counts.into_iter().map(|Count(n)| n).collect()
+}
@@ -0,0 +1,30 @@
+//! Derives and `#[accepts]` resolving itemize through `crate = path` only.
+
+use std::num::ParseIntError;
+
+use itemize_renamed::facade::inner::{IntoItems, IntoRows, TryIntoItems};
+use itemize_renamed::{Count, Int, counts};
+
+fn ints(input: impl TryIntoItems<Int, ParseIntError>) -> Result<Vec<Int>, ParseIntError> {
+    input.try_into_items().collect()
+}
+
+#[test]
+fn facade_path() {
+    let items: Vec<Count> = (1u32, 2u32).into_items().collect();
+    assert_eq!(items, vec![Count(1), Count(2)]);
+
+    let rows: Vec<Vec<Count>> = (vec![3u32, 4], 5u32)
+        .into_rows()
+        .map(Iterator::collect)
+        .collect();
+    assert_eq!(rows, vec![vec![Count(3), Count(4)], vec![Count(5)]]);
+
+    assert_eq!(counts((6, 7)), vec![6, 7]);
+}
+
+#[test]
+fn dependency_path() {
+    assert_eq!(ints(("8", "9")), Ok(vec![Int(8), Int(9)]));
+    assert!(ints(vec!["x"]).is_err());
+}